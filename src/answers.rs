@@ -0,0 +1,117 @@
+//! Registry of known-correct answers, so we can check that the solvers still work.
+//!
+//! Answers are stored in a TOML file with one table per day:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 24000
+//! part2 = "45000"
+//! ```
+//!
+//! Answers may be integers or strings. Multi-line answers (e.g., the CRT of day 10) are compared
+//! line by line, ignoring trailing blanks.
+
+use crate::error::location_of;
+use crate::{AocError, N_DAYS};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use toml::Spanned;
+
+/// Default location of the answers file.
+pub const ANSWERS_PATH: &str = "data/answers.toml";
+
+/// Raw answers of a day, as written in the file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDay {
+    part1: Option<Spanned<toml::Value>>,
+    part2: Option<Spanned<toml::Value>>,
+}
+
+/// Known answers of every day and part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    /// Answers indexed by (day, part).
+    answers: BTreeMap<(usize, usize), String>,
+}
+
+/// Result of comparing an answer with the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer matches the recorded one.
+    Pass,
+    /// The answer does not match the recorded one.
+    Fail { expected: String },
+    /// There is no recorded answer for this day and part.
+    Missing,
+}
+
+impl Answers {
+    /// It parses the content of an answers file.
+    pub fn parse(content: &str) -> Result<Self, AocError> {
+        let error = |offset: usize, msg: String| {
+            let offset = offset.min(content.len());
+            let (line, column) = location_of(content, &content[offset..]);
+            AocError::parse(line, column, msg)
+        };
+        let raw: BTreeMap<Spanned<String>, RawDay> = toml::from_str(content)
+            .map_err(|e| error(e.span().map_or(0, |s| s.start), e.message().to_string()))?;
+        let mut answers = BTreeMap::new();
+        for (key, day_answers) in raw {
+            let day = key
+                .get_ref()
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<usize>().ok())
+                .filter(|d| (1..=N_DAYS).contains(d))
+                .ok_or_else(|| {
+                    error(key.span().start, format!("unknown day {:?}", key.get_ref()))
+                })?;
+            for (part, value) in [(1, day_answers.part1), (2, day_answers.part2)] {
+                let Some(value) = value else { continue };
+                let answer = match value.get_ref() {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(n) => n.to_string(),
+                    other => {
+                        return Err(error(
+                            value.span().start,
+                            format!(
+                                "answers must be integers or strings, found {}",
+                                other.type_str()
+                            ),
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    /// It returns the recorded answer of a given day and part (if any).
+    pub fn get(&self, day: usize, part: usize) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// It compares an answer with the recorded one.
+    pub fn verify(&self, day: usize, part: usize, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if same_answer(expected, answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+/// It compares two answers line by line, ignoring trailing blanks.
+fn same_answer(a: &str, b: &str) -> bool {
+    let lines = |s: &str| {
+        let mut res: Vec<String> = s.lines().map(|l| l.trim_end().to_string()).collect();
+        while res.last().is_some_and(|l| l.is_empty()) {
+            res.pop();
+        }
+        res
+    };
+    lines(a.trim_start_matches('\n')) == lines(b.trim_start_matches('\n'))
+}
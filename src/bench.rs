@@ -0,0 +1,125 @@
+//! Benchmarks of the solutions.
+//!
+//! Every run times the parsing of the input and both parts of the puzzle separately.
+//! After several runs, we keep the minimum, median, and maximum time of every stage.
+
+use crate::{parse, AocError};
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Stages of a solution that we time separately.
+pub const STAGES: [&str; 3] = ["parse", "part 1", "part 2"];
+
+/// Time spent in every stage of a single run.
+pub type Timing = [Duration; STAGES.len()];
+
+/// It runs the solution of a day once and returns the time spent in every stage.
+pub fn time_day(day: usize, input: &str) -> Result<Timing, AocError> {
+    let start = Instant::now();
+    let parsed = parse(day, input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    parsed.answer1()?;
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    parsed.answer2()?;
+    let part2_time = start.elapsed();
+
+    Ok([parse_time, part1_time, part2_time])
+}
+
+/// Statistics of several runs of the same stage.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// It computes the statistics of a set of measurements.
+    /// If there are no measurements, it returns `None`.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let (min, max) = (*sorted.first()?, *sorted.last()?);
+        let n = sorted.len();
+        // With an even number of samples, the median is the mean of the two central samples
+        let median = match n % 2 {
+            0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            _ => sorted[n / 2],
+        };
+        Some(Self { min, median, max })
+    }
+}
+
+/// Benchmark of the solution of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    /// Day of the puzzle.
+    pub day: usize,
+    /// Number of runs.
+    pub runs: usize,
+    /// Statistics of every stage (see [`STAGES`]).
+    pub stages: [Stats; STAGES.len()],
+}
+
+/// It runs the solution of a day `runs` times and returns the statistics of every stage.
+pub fn bench(day: usize, input: &str, runs: usize) -> Result<Bench, AocError> {
+    let runs = runs.max(1); // We need at least one run to compute the statistics
+    let mut samples: [Vec<Duration>; STAGES.len()] = Default::default();
+    for _ in 0..runs {
+        for (stage, time) in samples.iter_mut().zip(time_day(day, input)?) {
+            stage.push(time);
+        }
+    }
+    Ok(Bench {
+        day,
+        runs,
+        stages: samples.map(|s| Stats::new(&s).unwrap_or_default()),
+    })
+}
+
+/// It formats a duration with a reasonable unit.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.3}s", nanos as f64 / 1e9)
+    }
+}
+
+/// It returns a summary table with the statistics of every day and stage.
+/// The last row contains the sum of the medians, which is a good estimate of the total time.
+pub fn table(benches: &[Bench]) -> String {
+    let mut res = String::new();
+    let _ = writeln!(
+        res,
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "min", "median", "max"
+    );
+    let mut total = Duration::ZERO;
+    for bench in benches {
+        for (stage, stats) in STAGES.iter().zip(bench.stages.iter()) {
+            let _ = writeln!(
+                res,
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+                format!("{:02}", bench.day),
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max)
+            );
+            total += stats.median;
+        }
+    }
+    let _ = writeln!(res, "total (sum of medians): {}", format_duration(total));
+    res
+}
@@ -0,0 +1,269 @@
+//! Cycle detection, so we can skip most of the iterations of long simulations.
+//!
+//! Simulations with a finite number of states eventually repeat themselves. Once we know where
+//! the [`Cycle`] starts and how long it is, we can tell the state of any iteration, and
+//! [`Cycle::extrapolate`] any quantity that grows the same amount in every lap (e.g., the height
+//! of the tower of day 17 after 10^12 rocks).
+//!
+//! There are three detectors. [`Memo`] remembers the key of every state it sees, so it finds the
+//! cycle as soon as it is completed, and it works with simulations that are updated in place.
+//! [`floyd`] and [`brent`] only keep a couple of states, so they need much less memory, but they
+//! need a pure step function (and they step the simulation more than once).
+//!
+//! ```
+//! use aoc_2022::cycle::{brent, floyd, Cycle, Memo};
+//!
+//! // x -> x^2 + 1 (mod 255) starting from 3: 3, 10, 101, 2, 5, 26, 167, 95, 101, 2, 5...
+//! let step = |x: &u32| (x * x + 1) % 255;
+//! let expected = Cycle { start: 2, length: 6 };
+//! assert_eq!(floyd(3, step, |&x| x), expected);
+//! assert_eq!(brent(3, step, |&x| x), expected);
+//!
+//! let mut memo = Memo::new();
+//! let (mut x, mut sums) = (3, vec![3_u64]);
+//! let cycle = loop {
+//!     if let Some(cycle) = memo.insert(x) {
+//!         break cycle;
+//!     }
+//!     x = step(&x);
+//!     sums.push(sums.last().unwrap() + x as u64);
+//! };
+//! assert_eq!(cycle, expected);
+//! // Sum of the first 10^9 + 1 elements of the sequence
+//! assert_eq!(cycle.extrapolate(1_000_000_000, &sums), Some(65999999857));
+//! ```
+
+use num::{NumCast, PrimInt};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cycle of a sequence of states. The state at iteration `start + length` is the same as the
+/// state at iteration `start`, and `start` and `length` are the smallest that satisfy this.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// First iteration of the first lap of the cycle.
+    pub start: usize,
+    /// Number of iterations of every lap.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// It returns the first iteration with the same state as iteration `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length,
+        }
+    }
+
+    /// It returns the value of a quantity at iteration `n`. `values` are the values of the
+    /// quantity from iteration 0 to at least `start + length`, and the quantity must grow
+    /// the same amount in every lap. It returns `None` if `values` are too short or the
+    /// result overflows.
+    pub fn extrapolate<T: PrimInt>(&self, n: usize, values: &[T]) -> Option<T> {
+        if n < values.len() {
+            return Some(values[n]);
+        }
+        let (first, next) = (self.start, self.start + self.length);
+        let lap = values.get(next)?.checked_sub(values.get(first)?)?;
+        let n_laps: T = NumCast::from((n - self.start) / self.length)?;
+        values[self.reduce(n)].checked_add(&lap.checked_mul(&n_laps)?)
+    }
+}
+
+/// Cycle detector that remembers the key of every state.
+#[derive(Debug, Clone)]
+pub struct Memo<K> {
+    /// Iteration at which we saw every key.
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq> Default for Memo<K> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq> Memo<K> {
+    /// It creates an empty detector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of states that we already saw.
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// It returns true if we did not see any state yet.
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// It adds the key of the next state (the first one is iteration 0).
+    /// If we already saw this key, it returns the cycle.
+    pub fn insert(&mut self, key: K) -> Option<Cycle> {
+        let n = self.seen.len();
+        match self.seen.get(&key) {
+            Some(&start) => Some(Cycle {
+                start,
+                length: n - start,
+            }),
+            None => {
+                self.seen.insert(key, n);
+                None
+            }
+        }
+    }
+}
+
+/// It finds the cycle of the sequence that starts with `initial` using Floyd's tortoise and hare.
+/// States are compared by their `key`. The sequence must eventually repeat itself.
+pub fn floyd<S, K: PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    // First, the hare runs twice as fast as the tortoise until they meet within the cycle
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+    // The distance between them is a multiple of the length, so they meet again at the start
+    let mut start = 0;
+    tortoise = initial;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    // Finally, the hare goes around the cycle once
+    let mut length = 1;
+    hare = step(&tortoise);
+    let start_key = key(&tortoise);
+    while key(&hare) != start_key {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// It finds the cycle of the sequence that starts with `initial` using Brent's algorithm.
+/// States are compared by their `key`. The sequence must eventually repeat itself.
+/// It usually steps the sequence fewer times than [`floyd`].
+pub fn brent<S: Clone, K: PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    // First, we look for the length: the tortoise teleports to the hare at every power of two
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = key(&initial);
+    let mut hare = step(&initial);
+    let mut hare_key = key(&hare);
+    while tortoise != hare_key {
+        if power == length {
+            tortoise = hare_key;
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        hare_key = key(&hare);
+        length += 1;
+    }
+    // Then, the hare starts `length` states ahead, so they meet at the start of the cycle
+    let (mut tortoise, mut hare) = (initial.clone(), initial);
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// It finds the cycle of the sequence with the three detectors.
+    fn detect(initial: u32, step: impl Fn(&u32) -> u32) -> [Cycle; 3] {
+        let mut memo = Memo::new();
+        let mut x = initial;
+        let memo = loop {
+            if let Some(cycle) = memo.insert(x) {
+                break cycle;
+            }
+            x = step(&x);
+        };
+        [
+            memo,
+            floyd(initial, &step, |&x| x),
+            brent(initial, &step, |&x| x),
+        ]
+    }
+
+    #[test]
+    fn cycle_from_the_start() {
+        let expected = Cycle {
+            start: 0,
+            length: 5,
+        };
+        assert_eq!(detect(0, |x| (x + 1) % 5), [expected; 3]);
+        assert_eq!(detect(3, |x| (x + 1) % 5), [expected; 3]);
+    }
+
+    #[test]
+    fn cycle_of_length_one() {
+        let fixed = Cycle {
+            start: 0,
+            length: 1,
+        };
+        assert_eq!(detect(7, |&x| x), [fixed; 3]);
+        // 20, 10, 5, 2, 1, 0, 0...
+        let halving = Cycle {
+            start: 5,
+            length: 1,
+        };
+        assert_eq!(detect(20, |x| x / 2), [halving; 3]);
+        assert_eq!(halving.reduce(1_000), 5);
+    }
+
+    #[test]
+    fn extrapolate_short_values() {
+        let cycle = Cycle {
+            start: 1,
+            length: 2,
+        };
+        // Every lap adds 10
+        let values = [0_u32, 5, 8, 15];
+        assert_eq!(cycle.extrapolate(2, &values), Some(8));
+        assert_eq!(cycle.extrapolate(5, &values), Some(25));
+        assert_eq!(cycle.extrapolate(6, &values), Some(28));
+        // We need the values up to `start + length`
+        assert_eq!(cycle.extrapolate(5, &values[..3]), None);
+        assert_eq!(cycle.extrapolate(5, &[] as &[u32]), None);
+    }
+
+    #[test]
+    fn extrapolate_overflow() {
+        let cycle = Cycle {
+            start: 0,
+            length: 1,
+        };
+        let values = [0_u8, 100];
+        assert_eq!(cycle.extrapolate(2, &values), Some(200));
+        assert_eq!(cycle.extrapolate(3, &values), None);
+        // The number of laps does not fit in the type of the values
+        assert_eq!(cycle.extrapolate(1_000, &[0_u8, 0]), None);
+        assert_eq!(cycle.extrapolate(1_000, &[0_u16, 0]), Some(0));
+    }
+}
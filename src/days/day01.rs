@@ -0,0 +1,283 @@
+//! Day 1: Calorie Counting.
+
+use crate::error::parse_token;
+use crate::{AocError, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+/// Reads the input and returns a vector of vectors.
+/// Each element of the outer vector corresponds to one elf.
+/// Inner vectors contain the calories of the food obtained by the elves.
+/// It keeps every item in memory, so use [`top_elves`] for huge inventories.
+pub fn read_input(input: &str) -> Result<Vec<Vec<usize>>, AocError> {
+    let mut res: Vec<Vec<usize>> = Vec::new();
+    // If ready is false, we need to add a new vector (i.e., a new elf) to res.
+    let mut ready = false;
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            // If blank line, we set ready to false and get ready to add a new vector to res
+            ready = false;
+            continue;
+        }
+        let calories = parse_token(i + 1, line, line)?;
+        // First, we check if we need to add a new vector to res.
+        if !ready {
+            res.push(Vec::new());
+            ready = true;
+        }
+        // We push the new food to the last elf.
+        if let Some(elf) = res.last_mut() {
+            elf.push(calories);
+        }
+    }
+    Ok(res)
+}
+
+/// It returns a vector with the sum of the calories of all the food obtained by the elves.
+pub fn sum_calories(elves: &[Vec<usize>]) -> Vec<usize> {
+    let mut res = Vec::new();
+    for food in elves {
+        res.push(food.iter().sum());
+    }
+    res
+}
+
+/// An elf and the calories of all the food that it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// Position of the elf in the input (the first elf is 0).
+    pub index: usize,
+    /// Sum of the calories of its food.
+    pub calories: usize,
+}
+
+/// It reads the inventory line by line and returns the `k` elves with the most calories,
+/// sorted in descending order (ties go to the elf that comes first).
+/// Neither the items nor the elves are kept in memory: we only keep a min-heap with the best
+/// `k` elves so far, so the input can be much larger than the available memory.
+pub fn top_elves(mut input: impl BufRead, k: usize) -> Result<Vec<Elf>, AocError> {
+    // The worst elf of the heap is on top, and it leaves as soon as a better elf arrives
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    let mut push = |elf: Elf| {
+        heap.push(Reverse((elf.calories, Reverse(elf.index))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    };
+    let (mut line, mut line_i) = (String::new(), 0);
+    // Calories of the current elf (None until we find its first item)
+    let (mut elf, mut n_elves) = (None, 0);
+    loop {
+        line.clear();
+        line_i += 1;
+        let n_bytes = input
+            .read_line(&mut line)
+            .map_err(|e| AocError::parse(line_i, 1, e.to_string()))?;
+        let item = line.trim();
+        if item.is_empty() {
+            // A blank line (or the end of the input) closes the current elf
+            if let Some(calories) = elf.take() {
+                push(Elf {
+                    index: n_elves,
+                    calories,
+                });
+                n_elves += 1;
+            }
+            match n_bytes {
+                0 => break,
+                _ => continue,
+            }
+        }
+        let calories: usize = parse_token(line_i, &line, item)?;
+        elf = Some(
+            elf.unwrap_or(0_usize)
+                .checked_add(calories)
+                .ok_or_else(|| AocError::parse(line_i, 1, "too many calories for one elf"))?,
+        );
+    }
+    // Sorting the reversed elves in ascending order leaves the best elf first
+    let res = heap.into_sorted_vec().into_iter();
+    Ok(res
+        .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+        .collect())
+}
+
+/// Inventory of every elf, ready to answer questions about the calories that they carry.
+/// Unlike [`top_elves`], it keeps the calories of every elf in memory (but not their items).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    /// Calories of every elf, in the order of the input.
+    calories: Vec<usize>,
+    /// Every elf, sorted by calories in descending order (ties go to the elf that comes first).
+    ranking: Vec<Elf>,
+}
+
+/// Elves whose calories are within a range (both ends included).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: usize,
+    pub end: usize,
+    /// Number of elves in the bucket.
+    pub count: usize,
+}
+
+impl Inventory {
+    /// It reads the inventory of the puzzle input.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self::new(sum_calories(&read_input(input)?)))
+    }
+
+    /// It creates an inventory from the calories of every elf.
+    pub fn new(calories: Vec<usize>) -> Self {
+        let mut ranking: Vec<_> = calories
+            .iter()
+            .enumerate()
+            .map(|(index, &calories)| Elf { index, calories })
+            .collect();
+        // The sort is stable, so ties keep the order of the input
+        ranking.sort_by_key(|elf| Reverse(elf.calories));
+        Self { calories, ranking }
+    }
+
+    /// Number of elves.
+    pub fn len(&self) -> usize {
+        self.calories.len()
+    }
+
+    /// It returns true if there are no elves.
+    pub fn is_empty(&self) -> bool {
+        self.calories.is_empty()
+    }
+
+    /// Calories carried by the `index`th elf (if it exists).
+    pub fn calories(&self, index: usize) -> Option<usize> {
+        self.calories.get(index).copied()
+    }
+
+    /// Calories carried by all the elves. It returns `None` if they do not fit in a `usize`.
+    pub fn total(&self) -> Option<usize> {
+        self.calories
+            .iter()
+            .try_fold(0_usize, |total, &calories| total.checked_add(calories))
+    }
+
+    /// Calories of the elf with the fewest calories.
+    pub fn min(&self) -> Option<usize> {
+        self.ranking.last().map(|elf| elf.calories)
+    }
+
+    /// Calories of the elf with the most calories.
+    pub fn max(&self) -> Option<usize> {
+        self.ranking.first().map(|elf| elf.calories)
+    }
+
+    /// Mean calories per elf. We add the calories as floats, so the mean exists even if
+    /// the [`total`](Self::total) does not fit in a `usize`.
+    pub fn mean(&self) -> Option<f64> {
+        match self.len() {
+            0 => None,
+            n => Some(self.calories.iter().map(|&c| c as f64).sum::<f64>() / n as f64),
+        }
+    }
+
+    /// Median calories per elf. With an even number of elves, it is the mean of the two middle ones.
+    pub fn median(&self) -> Option<f64> {
+        let n = self.len();
+        match n {
+            0 => None,
+            _ => {
+                let (a, b) = (&self.ranking[(n - 1) / 2], &self.ranking[n / 2]);
+                Some((a.calories as f64 + b.calories as f64) / 2.)
+            }
+        }
+    }
+
+    /// It returns the `p`th percentile (from 0 to 100) with the nearest-rank method:
+    /// the fewest calories such that at least `p`% of the elves carry the same or fewer calories.
+    /// It returns `None` if there are no elves or `p` is out of range.
+    pub fn percentile(&self, p: f64) -> Option<usize> {
+        if self.is_empty() || !(0. ..=100.).contains(&p) {
+            return None;
+        }
+        let n = self.len();
+        let rank = ((p / 100. * n as f64).ceil() as usize).clamp(1, n);
+        // The ranking goes from the most to the fewest calories
+        Some(self.ranking[n - rank].calories)
+    }
+
+    /// It splits the calories from the minimum to the maximum in `n_buckets` buckets of the
+    /// same width, and counts the elves of every bucket. Widths are integers, so the last bucket
+    /// may be narrower, and there may be fewer buckets than requested (e.g., 10 buckets for
+    /// calories from 0 to 10 need a width of 2, so we only get 6 buckets).
+    pub fn histogram(&self, n_buckets: usize) -> Vec<Bucket> {
+        let (Some(min), Some(max)) = (self.min(), self.max()) else {
+            return Vec::new();
+        };
+        if n_buckets == 0 {
+            return Vec::new();
+        }
+        let width = (max - min) / n_buckets + 1;
+        let mut res: Vec<_> = (0..n_buckets)
+            .map(|i| min + i * width)
+            .take_while(|&start| start <= max)
+            .map(|start| Bucket {
+                start,
+                end: max.min(start + width - 1),
+                count: 0,
+            })
+            .collect();
+        for &calories in &self.calories {
+            res[(calories - min) / width].count += 1;
+        }
+        res
+    }
+
+    /// The `k` elves with the most calories, sorted in descending order.
+    pub fn top(&self, k: usize) -> &[Elf] {
+        &self.ranking[..k.min(self.len())]
+    }
+
+    /// Elves that carry more than `threshold` calories, sorted in descending order.
+    pub fn above(&self, threshold: usize) -> &[Elf] {
+        let n = self.ranking.partition_point(|elf| elf.calories > threshold);
+        &self.ranking[..n]
+    }
+
+    /// Rank of the `index`th elf (if it exists): 1 for the elf with the most calories, 2 for the
+    /// next one, and so on. Elves with the same calories share their rank.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let calories = self.calories(index)?;
+        Some(self.above(calories).len() + 1)
+    }
+}
+
+/// Solution of the first day.
+#[derive(Debug, Clone)]
+pub struct Day01 {
+    /// The three elves with the most calories, sorted in descending order.
+    best: Vec<Elf>,
+}
+
+impl Solution for Day01 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        // We only need the best three elves, so we do not keep the rest
+        let best = top_elves(input.as_bytes(), 3)?;
+        Ok(Self { best })
+    }
+
+    /// Calories carried by the elf with the most calories.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self.best.iter().take(1).map(|elf| elf.calories).sum())
+    }
+
+    /// Calories carried by the best three elves.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self.best.iter().map(|elf| elf.calories).sum())
+    }
+}
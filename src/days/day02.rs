@@ -0,0 +1,523 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! The game is not hardwired: moves, what beats what, the letters of the strategy guide, and the
+//! points of every round come from [`Rules`], which we read from a TOML file like this one:
+//!
+//! ```toml
+//! [outcomes]
+//! lose = { letter = "X", points = 0 }
+//! draw = { letter = "Y", points = 3 }
+//! win = { letter = "Z", points = 6 }
+//!
+//! [[moves]]
+//! name = "rock"
+//! elf = "A"             # letter of the move in the elf's column
+//! me = "X"              # letter of the move in my column (when it is a move)
+//! points = 1            # points for playing it
+//! beats = ["scissors"]
+//! ```
+//!
+//! Instead of the `beats` of every move, `cyclic = true` makes a tournament where every move
+//! beats the (n - 1) / 2 moves that come before it (wrapping around), for any odd number of moves.
+
+use crate::error::{column_of, location_of};
+use crate::{AocError, Solution};
+use serde::Deserialize;
+use std::cmp::Reverse;
+use toml::Spanned;
+
+/// Rules of the puzzle statement: the classic rock, paper, scissors.
+pub const CLASSIC_RULES: &str = r#"
+[outcomes]
+lose = { letter = "X", points = 0 }
+draw = { letter = "Y", points = 3 }
+win = { letter = "Z", points = 6 }
+
+[[moves]]
+name = "rock"
+elf = "A"
+me = "X"
+points = 1
+beats = ["scissors"]
+
+[[moves]]
+name = "paper"
+elf = "B"
+me = "Y"
+points = 2
+beats = ["rock"]
+
+[[moves]]
+name = "scissors"
+elf = "C"
+me = "Z"
+points = 3
+beats = ["paper"]
+"#;
+
+/// A move of the game, identified by its position in the rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(pub usize);
+
+/// Result of a round, from my point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// Column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Player {
+    Elf,
+    Me,
+}
+
+/// A move and how it is written and scored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub name: String,
+    /// Letter of the move in the elf's column.
+    pub elf: String,
+    /// Letter of the move in my column (when my column is a move).
+    pub me: String,
+    /// Points for playing the move.
+    pub points: usize,
+}
+
+/// Raw outcome, as written in the rules file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawOutcome {
+    letter: Spanned<String>,
+    points: usize,
+}
+
+/// Raw outcomes, as written in the rules file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawOutcomes {
+    lose: RawOutcome,
+    draw: RawOutcome,
+    win: RawOutcome,
+}
+
+/// Raw move, as written in the rules file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawMove {
+    name: Spanned<String>,
+    elf: Spanned<String>,
+    me: Spanned<String>,
+    points: usize,
+    #[serde(default)]
+    beats: Vec<Spanned<String>>,
+}
+
+/// Raw rules, as written in the rules file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRules {
+    cyclic: Option<Spanned<bool>>,
+    outcomes: RawOutcomes,
+    moves: Vec<RawMove>,
+}
+
+/// Rules of a game like rock, paper, scissors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    moves: Vec<Move>,
+    /// `beats[a][b]` is true if move `a` beats move `b`.
+    beats: Vec<Vec<bool>>,
+    /// Letter and points of losing, drawing, and winning (in this order).
+    outcomes: [(String, usize); 3],
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::parse(CLASSIC_RULES).expect("the classic rules are valid")
+    }
+}
+
+impl Rules {
+    /// It parses a rules file. Errors point to the line and column of the problem.
+    pub fn parse(content: &str) -> Result<Self, AocError> {
+        let error = |offset: usize, msg: String| {
+            let offset = offset.min(content.len());
+            let (line, column) = location_of(content, &content[offset..]);
+            AocError::parse(line, column, msg)
+        };
+        let raw: RawRules = toml::from_str(content)
+            .map_err(|e| error(e.span().map_or(0, |s| s.start), e.message().to_string()))?;
+        if raw.moves.is_empty() {
+            return Err(error(0, "the game has no moves".to_string()));
+        }
+
+        // Names and letters must be unique, or we would not know which move is which
+        let letters = [
+            raw.moves.iter().map(|m| &m.name).collect::<Vec<_>>(),
+            raw.moves.iter().map(|m| &m.elf).collect(),
+            raw.moves.iter().map(|m| &m.me).collect(),
+            [&raw.outcomes.lose, &raw.outcomes.draw, &raw.outcomes.win]
+                .map(|o| &o.letter)
+                .to_vec(),
+        ];
+        for values in letters {
+            for (i, value) in values.iter().enumerate() {
+                if value.get_ref().split_whitespace().count() != 1 {
+                    let msg = format!("{:?} must be a single word", value.get_ref());
+                    return Err(error(value.span().start, msg));
+                }
+                if values[..i].iter().any(|v| v.get_ref() == value.get_ref()) {
+                    let msg = format!("{:?} is repeated", value.get_ref());
+                    return Err(error(value.span().start, msg));
+                }
+            }
+        }
+
+        let n = raw.moves.len();
+        let mut beats = vec![vec![false; n]; n];
+        if let Some(cyclic) = raw.cyclic.filter(|c| *c.get_ref()) {
+            if raw.moves.iter().any(|m| !m.beats.is_empty()) {
+                let msg = "cyclic games cannot have `beats`".to_string();
+                return Err(error(cyclic.span().start, msg));
+            }
+            if n.is_multiple_of(2) {
+                let msg = format!("cyclic games need an odd number of moves, found {}", n);
+                return Err(error(cyclic.span().start, msg));
+            }
+            for (a, row) in beats.iter_mut().enumerate() {
+                for i in 1..=(n - 1) / 2 {
+                    row[(a + n - i) % n] = true;
+                }
+            }
+        } else {
+            for (a, m) in raw.moves.iter().enumerate() {
+                for name in &m.beats {
+                    let b = raw
+                        .moves
+                        .iter()
+                        .position(|m| m.name.get_ref() == name.get_ref())
+                        .ok_or_else(|| {
+                            error(
+                                name.span().start,
+                                format!("unknown move {:?}", name.get_ref()),
+                            )
+                        })?;
+                    if a == b {
+                        let msg = format!("{:?} cannot beat itself", name.get_ref());
+                        return Err(error(name.span().start, msg));
+                    }
+                    if beats[b][a] {
+                        let msg = format!(
+                            "{:?} and {:?} cannot beat each other",
+                            m.name.get_ref(),
+                            name.get_ref()
+                        );
+                        return Err(error(name.span().start, msg));
+                    }
+                    beats[a][b] = true;
+                }
+            }
+        }
+
+        let outcome = |o: RawOutcome| (o.letter.into_inner(), o.points);
+        Ok(Self {
+            moves: raw
+                .moves
+                .into_iter()
+                .map(|m| Move {
+                    name: m.name.into_inner(),
+                    elf: m.elf.into_inner(),
+                    me: m.me.into_inner(),
+                    points: m.points,
+                })
+                .collect(),
+            beats,
+            outcomes: [
+                outcome(raw.outcomes.lose),
+                outcome(raw.outcomes.draw),
+                outcome(raw.outcomes.win),
+            ],
+        })
+    }
+
+    /// Moves of the game.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// It returns the move of a symbol.
+    pub fn get(&self, symbol: Symbol) -> &Move {
+        &self.moves[symbol.0]
+    }
+
+    /// Letter of an outcome in my column (when my column is an outcome).
+    pub fn outcome_letter(&self, outcome: Outcome) -> &str {
+        &self.outcomes[outcome as usize].0
+    }
+
+    /// Points for an outcome.
+    pub fn outcome_points(&self, outcome: Outcome) -> usize {
+        self.outcomes[outcome as usize].1
+    }
+
+    /// It parses the symbol of a player. If the symbol is unknown, it returns `None`.
+    pub fn parse_symbol(&self, player: Player, symbol: &str) -> Option<Symbol> {
+        let letter = |m: &Move| match player {
+            Player::Elf => m.elf == symbol,
+            Player::Me => m.me == symbol,
+        };
+        self.moves.iter().position(letter).map(Symbol)
+    }
+
+    /// It parses an outcome of my column. If the outcome is unknown, it returns `None`.
+    pub fn parse_outcome(&self, symbol: &str) -> Option<Outcome> {
+        [Outcome::Lose, Outcome::Draw, Outcome::Win]
+            .into_iter()
+            .find(|&o| self.outcome_letter(o) == symbol)
+    }
+
+    /// It returns the result of a round for me.
+    pub fn outcome(&self, elf: Symbol, me: Symbol) -> Outcome {
+        match (self.beats[me.0][elf.0], self.beats[elf.0][me.0]) {
+            (true, _) => Outcome::Win,
+            (_, true) => Outcome::Lose,
+            _ => Outcome::Draw,
+        }
+    }
+
+    /// It returns the move I must play to get an outcome against the elf's symbol.
+    /// If several moves do it, I play the one with the most points (the first one if they tie).
+    /// If no move does it, it returns `None`.
+    pub fn parse_my_symbol(&self, elf: Symbol, outcome: Outcome) -> Option<Symbol> {
+        (0..self.moves.len())
+            .map(Symbol)
+            .filter(|&me| self.outcome(elf, me) == outcome)
+            .min_by_key(|&me| Reverse(self.get(me).points))
+    }
+
+    /// Reads the input when my column is the move I must play (the first exercise).
+    pub fn read_input_1(&self, input: &str) -> Result<Vec<(Symbol, Symbol)>, AocError> {
+        let mut res = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let (elf, me) = split_line(i + 1, line)?;
+            let elf_symbol = self
+                .parse_symbol(Player::Elf, elf)
+                .ok_or_else(|| unknown_symbol(i + 1, line, elf))?;
+            let my_symbol = self
+                .parse_symbol(Player::Me, me)
+                .ok_or_else(|| unknown_symbol(i + 1, line, me))?;
+            res.push((elf_symbol, my_symbol));
+        }
+        Ok(res)
+    }
+
+    /// Reads the input when my column tells me whether I must win, draw, or lose
+    /// (the second exercise).
+    pub fn read_input_2(&self, input: &str) -> Result<Vec<(Symbol, Symbol)>, AocError> {
+        let mut res = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let (elf, me) = split_line(i + 1, line)?;
+            let elf_symbol = self
+                .parse_symbol(Player::Elf, elf)
+                .ok_or_else(|| unknown_symbol(i + 1, line, elf))?;
+            let outcome = self
+                .parse_outcome(me)
+                .ok_or_else(|| unknown_symbol(i + 1, line, me))?;
+            let my_symbol = self.parse_my_symbol(elf_symbol, outcome).ok_or_else(|| {
+                AocError::parse(
+                    i + 1,
+                    column_of(line, me),
+                    format!(
+                        "no move {} against {}",
+                        match outcome {
+                            Outcome::Lose => "loses",
+                            Outcome::Draw => "draws",
+                            Outcome::Win => "wins",
+                        },
+                        self.get(elf_symbol).name
+                    ),
+                )
+            })?;
+            res.push((elf_symbol, my_symbol));
+        }
+        Ok(res)
+    }
+
+    /// It computes the points I obtained in a given match.
+    pub fn match_points(&self, elf: Symbol, me: Symbol) -> usize {
+        self.get(me).points + self.outcome_points(self.outcome(elf, me))
+    }
+
+    /// It computes the total amount of points obtained in all the matches.
+    pub fn total_points(&self, plays: &[(Symbol, Symbol)]) -> usize {
+        plays
+            .iter()
+            .map(|&(elf, me)| self.match_points(elf, me))
+            .sum()
+    }
+
+    /// It returns the move that gets the most points against the elf's symbol
+    /// (the first one if they tie).
+    pub fn best_response(&self, elf: Symbol) -> Symbol {
+        (0..self.moves.len())
+            .map(Symbol)
+            .min_by_key(|&me| Reverse(self.match_points(elf, me)))
+            .unwrap_or(Symbol(0))
+    }
+
+    /// It reads the elf's column of the input and plays the best response to every symbol.
+    /// My column is ignored, as it could mean anything.
+    pub fn optimal_plays(&self, input: &str) -> Result<Vec<(Symbol, Symbol)>, AocError> {
+        let mut res = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let (elf, _) = split_line(i + 1, line)?;
+            let elf_symbol = self
+                .parse_symbol(Player::Elf, elf)
+                .ok_or_else(|| unknown_symbol(i + 1, line, elf))?;
+            res.push((elf_symbol, self.best_response(elf_symbol)));
+        }
+        Ok(res)
+    }
+
+    /// It returns every decoding of my column with its total score, from the best score to the
+    /// worst (ties keep the order of [`permutations`], so the decoding of the rules goes first).
+    pub fn decodings(&self, input: &str) -> Result<Vec<(Decoding, usize)>, AocError> {
+        let n = self.moves.len();
+        if n > MAX_DECODED_MOVES {
+            return Err(AocError::no_solution(format!(
+                "{} moves have too many decodings (the limit is {} moves)",
+                n, MAX_DECODED_MOVES
+            )));
+        }
+        // We read the guide once, and then we change the meaning of my symbols
+        let plays = self.read_input_1(input)?;
+        let mut res: Vec<_> = permutations(n)
+            .into_iter()
+            .map(|moves| {
+                let decoding = Decoding(moves.into_iter().map(Symbol).collect());
+                let decoded: Vec<_> = plays
+                    .iter()
+                    .map(|&(elf, me)| (elf, decoding.0[me.0]))
+                    .collect();
+                let score = self.total_points(&decoded);
+                (decoding, score)
+            })
+            .collect();
+        res.sort_by_key(|&(_, score)| Reverse(score));
+        Ok(res)
+    }
+
+    /// It describes a decoding (e.g., `X=rock Y=paper Z=scissors`).
+    pub fn describe(&self, decoding: &Decoding) -> String {
+        let letters = self.moves.iter().zip(&decoding.0);
+        let letters = letters.map(|(m, &symbol)| format!("{}={}", m.me, self.get(symbol).name));
+        letters.collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Most moves that [`Rules::decodings`] accepts (8 moves have 40320 decodings).
+pub const MAX_DECODED_MOVES: usize = 8;
+
+/// A meaning of the letters of my column: the letter of the `i`th move means the move `.0[i]`.
+/// The rules give the meaning where every letter means its own move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoding(pub Vec<Symbol>);
+
+/// It returns every permutation of `0..n` in lexicographic order.
+pub fn permutations(n: usize) -> Vec<Vec<usize>> {
+    let mut res = Vec::new();
+    let mut current: Vec<usize> = (0..n).collect();
+    loop {
+        res.push(current.clone());
+        // Next permutation: we increase the rightmost element that can be increased and then
+        // we sort the elements to its right
+        let Some(i) = (1..n).rev().find(|&i| current[i - 1] < current[i]) else {
+            return res;
+        };
+        let j = (i..n)
+            .rev()
+            .find(|&j| current[j] > current[i - 1])
+            .unwrap_or(i);
+        current.swap(i - 1, j);
+        current[i..].reverse();
+    }
+}
+
+/// It splits the `line_i`th line of the strategy guide into the elf's column and my column.
+fn split_line(line_i: usize, line: &str) -> Result<(&str, &str), AocError> {
+    let mut x = line.split_whitespace();
+    match (x.next(), x.next(), x.next()) {
+        (Some(elf), Some(me), None) => Ok((elf, me)),
+        (_, _, Some(extra)) => Err(AocError::parse(
+            line_i,
+            column_of(line, extra),
+            "too many columns",
+        )),
+        _ => Err(AocError::parse(
+            line_i,
+            line.chars().count() + 1,
+            "expected two columns",
+        )),
+    }
+}
+
+/// Error for unknown symbols in the strategy guide.
+fn unknown_symbol(line_i: usize, line: &str, symbol: &str) -> AocError {
+    AocError::parse(
+        line_i,
+        column_of(line, symbol),
+        format!("unknown symbol {:?}", symbol),
+    )
+}
+
+/// Solution of the second day.
+#[derive(Debug, Clone)]
+pub struct Day02 {
+    rules: Rules,
+    /// Plays when the second column is the symbol I must play.
+    plays_1: Result<Vec<(Symbol, Symbol)>, AocError>,
+    /// Plays when the second column tells me whether I must win, draw, or lose.
+    plays_2: Result<Vec<(Symbol, Symbol)>, AocError>,
+}
+
+impl Day02 {
+    /// It reads the strategy guide of a game with different rules.
+    /// A guide may only make sense with one of the interpretations of my column (e.g., it has
+    /// more moves than outcomes), so an interpretation that fails only affects its part.
+    /// If both interpretations fail, it returns the error of the first one.
+    pub fn with_rules(rules: Rules, input: &str) -> Result<Self, AocError> {
+        let (plays_1, plays_2) = (rules.read_input_1(input), rules.read_input_2(input));
+        if let (Err(e), Err(_)) = (&plays_1, &plays_2) {
+            return Err(e.clone());
+        }
+        Ok(Self {
+            rules,
+            plays_1,
+            plays_2,
+        })
+    }
+}
+
+impl Solution for Day02 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Self::with_rules(Rules::default(), input)
+    }
+
+    /// Total score if the second column is the symbol I must play.
+    fn part1(&self) -> Result<usize, AocError> {
+        let plays = self.plays_1.as_ref().map_err(Clone::clone)?;
+        Ok(self.rules.total_points(plays))
+    }
+
+    /// Total score if the second column tells me whether I must win, draw, or lose.
+    fn part2(&self) -> Result<usize, AocError> {
+        let plays = self.plays_2.as_ref().map_err(Clone::clone)?;
+        Ok(self.rules.total_points(plays))
+    }
+}
@@ -0,0 +1,297 @@
+//! Day 3: Rucksack Reorganization.
+
+use crate::{AocError, Solution};
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitOr, Sub};
+use std::str::FromStr;
+
+/// Compartments of every rucksack in the puzzle.
+pub const COMPARTMENTS: usize = 2;
+
+/// Elves of every group in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+/// Returns a numeric priority from an ASCII letter (1 to 26 for `a` to `z`, and 27 to 52 for
+/// `A` to `Z`). Other characters are not items, so they have no priority.
+pub fn get_priority(item: char) -> Option<usize> {
+    match item {
+        'a'..='z' => Some(item as usize - 'a' as usize + 1),
+        'A'..='Z' => Some(item as usize - 'A' as usize + 27),
+        _ => None,
+    }
+}
+
+/// It returns the item of a priority (the opposite of [`get_priority`]).
+fn get_item(priority: usize) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        _ => (b'A' + (priority - 27) as u8) as char,
+    }
+}
+
+/// Set of items, with one bit per priority. Set operations are just bitwise operations,
+/// so finding the items in common of any number of rucksacks is linear in their size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Set with no items.
+    pub const EMPTY: Self = Self(0);
+    /// Set with the 52 items.
+    pub const ALL: Self = Self(((1 << 52) - 1) << 1);
+
+    /// It adds an item to the set. It returns false if the character is not an item.
+    pub fn insert(&mut self, item: char) -> bool {
+        match get_priority(item) {
+            Some(priority) => {
+                self.0 |= 1 << priority;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// It returns true if the set contains the item.
+    pub fn contains(&self, item: char) -> bool {
+        get_priority(item).is_some_and(|priority| self.0 & (1 << priority) != 0)
+    }
+
+    /// Number of items in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// It returns true if the set has no items.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Items in any of both sets.
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Items in both sets.
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Items in this set but not in the other one.
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// It returns the items of the set, sorted by priority.
+    pub fn items(&self) -> impl Iterator<Item = char> + '_ {
+        (1..=52)
+            .filter(|&priority| self.0 & (1 << priority) != 0)
+            .map(get_item)
+    }
+
+    /// It returns the items in all the sets (or every item if there are no sets).
+    pub fn common(sets: impl IntoIterator<Item = Self>) -> Self {
+        sets.into_iter().fold(Self::ALL, Self::intersection)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for ItemSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    /// Characters that are not items are ignored.
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut res = Self::EMPTY;
+        for item in iter {
+            res.insert(item);
+        }
+        res
+    }
+}
+
+impl FromStr for ItemSet {
+    type Err = AocError;
+
+    /// It parses the items of a line. Every character must be an item.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut res = Self::EMPTY;
+        for (j, c) in s.chars().enumerate() {
+            if !res.insert(c) {
+                return Err(AocError::unexpected_char(1, j + 1, c));
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.items().try_for_each(|item| write!(f, "{}", item))
+    }
+}
+
+/// Items of a bag, with one set per compartment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bag(Vec<ItemSet>);
+
+impl Bag {
+    /// It returns the items in all the compartments of the bag.
+    fn duplicates(&self) -> ItemSet {
+        ItemSet::common(self.0.iter().copied())
+    }
+
+    /// It returns every item of the bag.
+    fn items(&self) -> ItemSet {
+        self.0.iter().fold(ItemSet::EMPTY, |res, &set| res | set)
+    }
+}
+
+/// Reads the input and returns the items of every bag.
+/// Bags can only contain ASCII letters, and all the compartments must have the same number of items.
+fn read_input(input: &str, compartments: usize) -> Result<Vec<Bag>, AocError> {
+    let mut res = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        // Position of every character in the line (and of the end of the line)
+        let bounds: Vec<_> = line
+            .char_indices()
+            .map(|(b, _)| b)
+            .chain([line.len()])
+            .collect();
+        let n_items = bounds.len() - 1;
+        if !n_items.is_multiple_of(compartments) {
+            return Err(AocError::parse(
+                i + 1,
+                1,
+                format!(
+                    "bags must split into {} compartments of the same size",
+                    compartments
+                ),
+            ));
+        }
+        let size = n_items / compartments;
+        let bag = (0..compartments).map(|k| {
+            line[bounds[k * size]..bounds[(k + 1) * size]]
+                .parse::<ItemSet>()
+                .map_err(|e| e.offset_lines(i).offset_columns(k * size))
+        });
+        res.push(Bag(bag.collect::<Result<_, _>>()?));
+    }
+    Ok(res)
+}
+
+/// It returns the only item of a set. `what` describes where the items come from.
+fn only_item(items: ItemSet, what: impl Fn() -> String) -> Result<char, AocError> {
+    match items.len() {
+        1 => Ok(items.items().next().unwrap_or_default()),
+        0 => Err(AocError::no_solution(format!(
+            "{} has no common items",
+            what()
+        ))),
+        n => {
+            let items: Vec<_> = items.items().map(String::from).collect();
+            Err(AocError::no_solution(format!(
+                "{} has {} common items ({})",
+                what(),
+                n,
+                items.join(", ")
+            )))
+        }
+    }
+}
+
+/// Exercise 1: sums the priority code of the item in all the compartments of every bag.
+/// Every bag must have exactly one item in all its compartments.
+fn exercise_1(bags: &[Bag]) -> Result<usize, AocError> {
+    let mut res = 0;
+    for (i, bag) in bags.iter().enumerate() {
+        let duplicate = only_item(bag.duplicates(), || format!("bag {}", i + 1))?;
+        res += get_priority(duplicate).unwrap_or_default();
+    }
+    Ok(res)
+}
+
+/// Exercise 2: sums the priority code of all group badges.
+/// Every group must have exactly one item (its badge) in all its bags.
+fn exercise_2(bags: &[Bag], group_size: usize) -> Result<usize, AocError> {
+    if !bags.len().is_multiple_of(group_size) {
+        return Err(AocError::no_solution(format!(
+            "the number of bags is not a multiple of {}",
+            group_size
+        )));
+    }
+    let mut res = 0;
+    for (i, group) in bags.chunks(group_size).enumerate() {
+        let badges = ItemSet::common(group.iter().map(Bag::items));
+        let badge = only_item(badges, || format!("group {}", i + 1))?;
+        res += get_priority(badge).unwrap_or_default();
+    }
+    Ok(res)
+}
+
+/// Solution of the third day.
+#[derive(Debug, Clone)]
+pub struct Day03 {
+    /// Items of every bag.
+    bags: Vec<Bag>,
+    /// Elves of every group.
+    group_size: usize,
+}
+
+impl Day03 {
+    /// It reads the bags of elves with a different number of compartments per bag or of elves
+    /// per group (the puzzle has 2 and 3).
+    pub fn with_sizes(
+        input: &str,
+        compartments: usize,
+        group_size: usize,
+    ) -> Result<Self, AocError> {
+        if compartments == 0 || group_size == 0 {
+            return Err(AocError::invalid_argument(
+                "bags need at least one compartment, and groups at least one elf",
+            ));
+        }
+        Ok(Self {
+            bags: read_input(input, compartments)?,
+            group_size,
+        })
+    }
+}
+
+impl Solution for Day03 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Self::with_sizes(input, COMPARTMENTS, GROUP_SIZE)
+    }
+
+    /// Sum of the priorities of the items that appear in all the compartments of every bag.
+    fn part1(&self) -> Result<usize, AocError> {
+        exercise_1(&self.bags)
+    }
+
+    /// Sum of the priorities of the badges of every group.
+    fn part2(&self) -> Result<usize, AocError> {
+        exercise_2(&self.bags, self.group_size)
+    }
+}
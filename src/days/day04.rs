@@ -0,0 +1,95 @@
+//! Day 4: Camp Cleanup.
+
+use crate::error::parse_token;
+use crate::{AocError, Solution};
+
+/// Auxiliary type for referring to the areas that an elf must cover.
+pub type ElfAreas = (usize, usize);
+
+/// Reads the input and returns a vector with the pairs of area ranges.
+fn read_input(input: &str) -> Result<Vec<(ElfAreas, ElfAreas)>, AocError> {
+    let mut res = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let x = line
+            .split(['-', ','])
+            .map(|c| parse_token::<usize>(i + 1, line, c))
+            .collect::<Result<Vec<_>, _>>()?;
+        if x.len() != 4 {
+            return Err(AocError::parse(
+                i + 1,
+                1,
+                format!("expected two ranges, found {} numbers", x.len()),
+            ));
+        }
+        let (elf_1, elf_2) = ((x[0], x[1]), (x[2], x[3]));
+        if elf_1.0 > elf_1.1 || elf_2.0 > elf_2.1 {
+            return Err(AocError::parse(
+                i + 1,
+                1,
+                "ranges must be in increasing order",
+            ));
+        }
+        res.push((elf_1, elf_2));
+    }
+    Ok(res)
+}
+
+/// Returns true if the areas of the two elves fully overlap.
+pub fn fully_overlaps(elf_1: &ElfAreas, elf_2: &ElfAreas) -> bool {
+    (elf_1.0 <= elf_2.0 && elf_1.1 >= elf_2.1) || (elf_1.0 >= elf_2.0 && elf_1.1 <= elf_2.1)
+}
+
+/// Returns true if the areas of the two elves overlap at least in one area.
+pub fn overlaps(elf_1: &ElfAreas, elf_2: &ElfAreas) -> bool {
+    (elf_1.0 <= elf_2.0 && elf_1.1 >= elf_2.0) || (elf_1.0 >= elf_2.0 && elf_1.0 <= elf_2.1)
+}
+
+/// Function for the first exercise
+fn exercise_1(areas: &[(ElfAreas, ElfAreas)]) -> usize {
+    let mut res = 0;
+    for (elf_1, elf_2) in areas {
+        if fully_overlaps(elf_1, elf_2) {
+            res += 1;
+        }
+    }
+    res
+}
+
+/// Function for the second exercise
+fn exercise_2(areas: &[(ElfAreas, ElfAreas)]) -> usize {
+    let mut res = 0;
+    for (elf_1, elf_2) in areas {
+        if overlaps(elf_1, elf_2) {
+            res += 1;
+        }
+    }
+    res
+}
+
+/// Solution of the fourth day.
+#[derive(Debug, Clone)]
+pub struct Day04 {
+    /// Pairs of area ranges.
+    areas: Vec<(ElfAreas, ElfAreas)>,
+}
+
+impl Solution for Day04 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            areas: read_input(input)?,
+        })
+    }
+
+    /// Number of pairs in which one range fully contains the other.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&self.areas))
+    }
+
+    /// Number of pairs in which the ranges overlap.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(exercise_2(&self.areas))
+    }
+}
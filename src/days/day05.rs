@@ -0,0 +1,167 @@
+//! Day 5: Supply Stacks.
+
+use crate::parser::{
+    complete, delimited, either, item_if, keyword, line, lines, many, preceded, separated1, spaces,
+    tag, terminated, unsigned, Input, PResult, Parser,
+};
+use crate::{AocError, Solution};
+
+type Scenario = Vec<Vec<char>>;
+type Move = (usize, usize, usize); // (n, from, to)
+
+/// It parses a row of crates. Every slot is either a crate (e.g., `[A]`) or three spaces.
+/// Trailing spaces may be trimmed by text editors, so rows may have fewer slots than stacks.
+fn crates(input: Input<'_>) -> PResult<'_, Vec<Option<char>>> {
+    let item = item_if(|c| c.is_ascii_uppercase(), "a crate");
+    let slot = either(
+        delimited(tag("["), item, tag("]")).map(Some),
+        tag("   ").map(|_| None),
+    );
+    separated1(slot, tag(" ")).parse(input)
+}
+
+/// It parses a move (e.g., `move 1 from 2 to 1`). Only stacks between 1 and `n_stacks` exist.
+fn step<'a>(input: Input<'a>, n_stacks: usize) -> PResult<'a, Move> {
+    let stack = || {
+        unsigned().try_map(move |stack| match stack == 0 || stack > n_stacks {
+            true => Err(format!("stack {} does not exist", stack)),
+            false => Ok(stack),
+        })
+    };
+    let (n, input) = keyword("move", unsigned()).parse(input)?;
+    let (from, input) = keyword("from", stack()).parse(input)?;
+    let (to, input) = keyword("to", stack()).parse(input)?;
+    Ok(((n, from, to), input))
+}
+
+/// Reads the input and returns the initial scenario, the moves to be done, and the line of the first move.
+fn read_input(input: &str) -> Result<(Scenario, Vec<Move>, usize), AocError> {
+    let (rows, rest) = many(line(crates)).parse(Input::new(input))?;
+    // Then, we find the indices of the stacks
+    let indices = preceded(spaces(), many(terminated(unsigned::<usize>(), spaces())));
+    let (indices, rest) = line(indices.label("the indices of the stacks")).parse(rest)?;
+    let mut scenario: Scenario = vec![Vec::new(); indices.len()];
+    // Now, we push the crates from bottom to top
+    for (i, row) in rows.iter().enumerate().rev() {
+        for (col, slot) in row.iter().enumerate() {
+            match (slot, scenario.get_mut(col)) {
+                (Some(val), Some(stack)) => stack.push(*val),
+                (Some(_), None) => {
+                    return Err(AocError::parse(
+                        i + 1,
+                        col * 4 + 1,
+                        "this crate has no stack",
+                    ))
+                }
+                (None, _) => {}
+            }
+        }
+    }
+    // Finally, the moves come after a blank line
+    let (_, rest) = line(spaces()).label("a blank line").parse(rest)?;
+    let n_stacks = scenario.len();
+    let first_line = rest.location().0;
+    let moves = complete(&lines(|i| step(i, n_stacks)), rest)?;
+    Ok((scenario, moves, first_line))
+}
+
+/// Error for moves that take more crates than those available in the stack.
+/// Moves are one per line, so the move `i` is in the line `first_line + i`.
+fn not_enough_crates(
+    first_line: usize,
+    i: usize,
+    (n, from, _): Move,
+    available: usize,
+) -> AocError {
+    AocError::parse(
+        first_line + i,
+        1,
+        format!(
+            "this move takes {} crates from stack {}, but it only has {}",
+            n, from, available
+        ),
+    )
+}
+
+/// Sorts the crates one by one.
+fn sort_cargo_1(
+    scenario: &mut Scenario,
+    moves: &[Move],
+    first_line: usize,
+) -> Result<(), AocError> {
+    for (i, &(n, from, to)) in moves.iter().enumerate() {
+        let len_from = scenario[from - 1].len();
+        if n > len_from {
+            return Err(not_enough_crates(first_line, i, moves[i], len_from));
+        }
+        for _ in 0..n {
+            let val = scenario[from - 1].pop().expect("we checked the length");
+            scenario[to - 1].push(val);
+        }
+    }
+    Ok(())
+}
+
+/// Sorts the crates in stacks
+fn sort_cargo_2(
+    scenario: &mut Scenario,
+    moves: &[Move],
+    first_line: usize,
+) -> Result<(), AocError> {
+    for (i, (n, from, to)) in moves.iter().enumerate() {
+        let len_from = scenario[*from - 1].len();
+        if *n > len_from {
+            return Err(not_enough_crates(first_line, i, moves[i], len_from));
+        }
+        let vals: Vec<char> = scenario[*from - 1].drain(len_from - n..).collect();
+        scenario[*to - 1].extend(vals);
+    }
+    Ok(())
+}
+
+/// Auxiliary function to get the top of the columns.
+fn get_top(scenario: &Scenario) -> String {
+    scenario
+        .iter()
+        .map(|row| *row.last().unwrap_or(&' '))
+        .collect()
+}
+
+/// Solution of the fifth day.
+#[derive(Debug, Clone)]
+pub struct Day05 {
+    /// Initial scenario.
+    scenario: Scenario,
+    /// Moves to be done.
+    moves: Vec<Move>,
+    /// Line of the first move in the input (for errors).
+    first_line: usize,
+}
+
+impl Solution for Day05 {
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let (scenario, moves, first_line) = read_input(input)?;
+        Ok(Self {
+            scenario,
+            moves,
+            first_line,
+        })
+    }
+
+    /// The crane moves the crates one by one.
+    fn part1(&self) -> Result<String, AocError> {
+        let mut scenario = self.scenario.clone();
+        sort_cargo_1(&mut scenario, &self.moves, self.first_line)?;
+        Ok(get_top(&scenario))
+    }
+
+    /// The crane moves the crates in stacks.
+    fn part2(&self) -> Result<String, AocError> {
+        let mut scenario = self.scenario.clone();
+        sort_cargo_2(&mut scenario, &self.moves, self.first_line)?;
+        Ok(get_top(&scenario))
+    }
+}
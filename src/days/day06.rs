@@ -0,0 +1,88 @@
+//! Day 6: Tuning Trouble.
+
+use crate::{AocError, Solution};
+
+/// It detects a sequence of n different characters in code.
+/// If there is no such sequence, it returns `None`.
+fn detect_sequence(code: &str, n: usize) -> Option<usize> {
+    // First, we crete a vector of characters
+    let chars: Vec<char> = code.chars().collect();
+    // Educated guess: where the sequence of different characters start
+    for start in 0..(chars.len() + 1).saturating_sub(n) {
+        let mut hit = false; // If set to true, there is a repetition in the sequence
+                             // We go from start to the end of the sequence (minus the last character)...
+        for i in start..start + n - 1 {
+            let char_i = chars[i];
+            // ... and check that the rest of the characters of the sequence are different
+            for char_j in chars.iter().take(start + n).skip(i + 1) {
+                // If there is a hit, we note it down and exit to try with the next sequence
+                if char_i == *char_j {
+                    hit = true;
+                    break;
+                }
+            }
+            if hit {
+                break;
+            }
+        }
+        // If hit is false at this point, then we found the sequence!
+        if !hit {
+            return Some(start + n);
+        }
+    }
+    // If we reach this part, then there is no seuquence that long in the text.
+    None
+}
+
+/// It detects a marker of n different characters and returns a proper error if there is none.
+fn detect_marker(code: &str, n: usize) -> Result<usize, AocError> {
+    detect_sequence(code, n).ok_or_else(|| {
+        AocError::no_solution(format!(
+            "there is no sequence of {} different characters",
+            n
+        ))
+    })
+}
+
+/// Solution of the sixth day.
+#[derive(Debug, Clone)]
+pub struct Day06 {
+    /// Datastream received by the device.
+    code: String,
+}
+
+impl Solution for Day06 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        // The datastream is a single line without blanks
+        let mut lines = input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        let (i, code) = lines
+            .next()
+            .ok_or_else(|| AocError::unexpected_end(1, "the datastream"))?;
+        if let Some((j, _)) = lines.next() {
+            return Err(AocError::parse(j + 1, 1, "expected a single datastream"));
+        }
+        let code = code.trim_end();
+        if let Some((j, c)) = code.chars().enumerate().find(|(_, c)| c.is_whitespace()) {
+            return Err(AocError::unexpected_char(i + 1, j + 1, c));
+        }
+        Ok(Self {
+            code: code.to_string(),
+        })
+    }
+
+    /// Start-of-packet marker (4 different characters).
+    fn part1(&self) -> Result<usize, AocError> {
+        detect_marker(&self.code, 4)
+    }
+
+    /// Start-of-message marker (14 different characters).
+    fn part2(&self) -> Result<usize, AocError> {
+        detect_marker(&self.code, 14)
+    }
+}
@@ -0,0 +1,194 @@
+//! Day 7: No Space Left On Device.
+
+use crate::error::{column_of, parse_token};
+use crate::{AocError, Solution};
+
+/// Struct for representing the filesystem
+#[derive(Debug, Clone)]
+struct Directory {
+    /// Vector of subdirectories
+    subdirs: Vec<Directory>,
+    /// Aggregated size of subfiles
+    files: usize,
+}
+
+impl Directory {
+    /// Creates a new empty directory
+    fn new() -> Self {
+        Self {
+            subdirs: Vec::new(),
+            files: 0,
+        }
+    }
+
+    /// Returns the total size of the directory
+    fn size(&self) -> usize {
+        let mut res = self.files;
+        for subdir in self.subdirs.iter() {
+            res += subdir.size();
+        }
+        res
+    }
+
+    /// Adds a subfile to the directory
+    fn add_file(&mut self, file_size: usize) {
+        self.files += file_size;
+    }
+
+    /// Adds a subdirectory to the directory
+    fn add_subdir(&mut self, subdir: Directory) {
+        self.subdirs.push(subdir);
+    }
+}
+
+/// Adds the directory on top of the stack as a subdirectory of the next directory.
+/// It returns false if there is no parent directory.
+fn close_dir(dir_stack: &mut Vec<Directory>) -> bool {
+    if dir_stack.len() < 2 {
+        return false;
+    }
+    if let Some(subdir) = dir_stack.pop() {
+        if let Some(parent) = dir_stack.last_mut() {
+            parent.add_subdir(subdir);
+        }
+    }
+    true
+}
+
+/// Reads the input and returns the entire filesystem.
+fn read_input(input: &str) -> Result<Directory, AocError> {
+    // We will follow a stack approach to complete the tree.
+    let mut dir_stack: Vec<Directory> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let command: Vec<&str> = line.split_whitespace().collect();
+        match command.as_slice() {
+            [] => continue,
+            ["$", "cd", ".."] => {
+                // The cd .. command tell us that the directory on top of the stack is done
+                // We pop it from the stack and add it as a subdirectory to the next directory.
+                if !close_dir(&mut dir_stack) {
+                    return Err(AocError::parse(
+                        i + 1,
+                        column_of(line, command[2]),
+                        "the root directory has no parent",
+                    ));
+                }
+            }
+            // The cd <dirname> tell us that we need to add a new directory to the stack
+            ["$", "cd", _] => dir_stack.push(Directory::new()),
+            ["$", "ls"] => continue, // We can ignore this command
+            ["$", cmd, ..] => {
+                return Err(AocError::parse(
+                    i + 1,
+                    column_of(line, cmd),
+                    format!("unknown command {:?}", cmd),
+                ))
+            }
+            ["dir", _] => continue, // We can ignore this line
+            [size, _] => {
+                // If the line represents a file, we add its size to the directory on top of the stack
+                let file_size = parse_token(i + 1, line, size)?;
+                match dir_stack.last_mut() {
+                    Some(dir) => dir.add_file(file_size),
+                    None => {
+                        return Err(AocError::parse(
+                            i + 1,
+                            1,
+                            "file listed outside of any directory",
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(AocError::parse(
+                    i + 1,
+                    1,
+                    format!("unexpected line {:?}", line),
+                ))
+            }
+        }
+    }
+    // After processing the file, we consume the stack until there is only the root directory
+    while close_dir(&mut dir_stack) {}
+    // We return the root directory
+    dir_stack
+        .pop()
+        .ok_or_else(|| AocError::unexpected_end(input.lines().count() + 1, "a cd command"))
+}
+
+/// We sum the size of all the directories whose size is less than or equal to `at_most`.
+fn exercise_1(dir: &Directory, at_most: usize) -> usize {
+    let mut res = 0;
+    let root_size = dir.size();
+    if root_size <= at_most {
+        res += root_size;
+    }
+    for item in dir.subdirs.iter() {
+        res += exercise_1(item, at_most);
+    }
+    res
+}
+
+/// We return the size of the smallest directory that we can remove while saving `space` bits.
+fn exercise_2(dir: &Directory, space: usize) -> Option<usize> {
+    let dir_size = dir.size();
+    if dir_size < space {
+        // This directory is too small and we cannot consider to remove it to save space
+        return None;
+    }
+    // So far, the best option is to remove this directory.
+    let mut min_size = dir_size;
+    // Let's look iteratively through the subdirectories for a better solution:
+    for subdir in dir.subdirs.iter() {
+        if let Some(subdir_size) = exercise_2(subdir, space) {
+            // If the subdirectory (or one of its subdirectories) is big enough
+            // but smaller than the currently best solution, we update min_size
+            if subdir_size < min_size {
+                min_size = subdir_size;
+            }
+        }
+    }
+    Some(min_size)
+}
+
+/// Total disk space available to the filesystem.
+const DISK_SPACE: usize = 70000000;
+/// Unused space needed to run the update.
+const UPDATE_SPACE: usize = 30000000;
+
+/// Solution of the seventh day.
+#[derive(Debug, Clone)]
+pub struct Day07 {
+    /// Root directory of the filesystem.
+    root: Directory,
+}
+
+impl Solution for Day07 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            root: read_input(input)?,
+        })
+    }
+
+    /// Sum of the sizes of the directories with a total size of at most 100000.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&self.root, 100000))
+    }
+
+    /// Size of the smallest directory that frees up enough space for the update.
+    fn part2(&self) -> Result<usize, AocError> {
+        let used_space = self.root.size();
+        if used_space > DISK_SPACE {
+            return Err(AocError::no_solution(
+                "the filesystem does not fit in the disk",
+            ));
+        }
+        let required_space = UPDATE_SPACE.saturating_sub(DISK_SPACE - used_space);
+        exercise_2(&self.root, required_space)
+            .ok_or_else(|| AocError::no_solution("no directory is big enough"))
+    }
+}
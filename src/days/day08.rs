@@ -0,0 +1,247 @@
+//! Day 8: Treetop Tree House.
+
+use crate::export::{Image, Paint, Palette, Render, Rgb};
+use crate::geom::{Direction, Point};
+use crate::grid::Grid;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+
+/// Reads the input and returns the scenario.
+/// The scenario must be a non-empty rectangle of digits.
+fn read_input(input: &str) -> Result<Grid<usize>, AocError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as usize))
+}
+
+/// It returns true if the tree in `point` is visible from the outside.
+/// A tree is visible if all the trees between it and any edge are shorter.
+/// Trees in the edges are always visible, as there is nothing between them and the edge.
+fn visible(scenario: &Grid<usize>, point: Point) -> bool {
+    let tree = scenario[point];
+    Direction::ALL
+        .into_iter()
+        .any(|d| scenario.ray(point, d).all(|other| scenario[other] < tree))
+}
+
+/// It returns the number of trees that are visible from the outside
+fn exercise_1(scenario: &Grid<usize>) -> usize {
+    scenario
+        .iter()
+        .filter(|&(point, _)| visible(scenario, point))
+        .count()
+}
+
+/// It returns the number of trees that we can see from `point` looking towards `direction`.
+/// We stop at the edge or at the first tree that is at least as tall as the tree in `point`.
+fn viewing_distance(scenario: &Grid<usize>, point: Point, direction: Direction) -> usize {
+    let tree = scenario[point];
+    let mut res = 0;
+    for other in scenario.ray(point, direction) {
+        res += 1;
+        if scenario[other] >= tree {
+            break;
+        }
+    }
+    res
+}
+
+/// It returns the scenic score of the tree in `point`.
+fn scenic_score(scenario: &Grid<usize>, point: Point) -> usize {
+    // The tree's score is the multiplication of all the directions
+    Direction::ALL
+        .into_iter()
+        .map(|d| viewing_distance(scenario, point, d))
+        .product()
+}
+
+/// It returns the highest scenic score possible for any tree in the scenario.
+fn exercise_2(scenario: &Grid<usize>) -> usize {
+    scenario
+        .iter()
+        .map(|(point, _)| scenic_score(scenario, point))
+        .max()
+        .unwrap_or_default()
+}
+
+/// What we know about every tree in the animation (with its height).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    /// Tree that we have not checked yet.
+    Unknown(usize),
+    Visible(usize),
+    Hidden(usize),
+    /// Tree with the highest scenic score so far.
+    Best(usize),
+    /// Tree that can be seen from the best tree.
+    Sight(usize),
+}
+
+impl Cell {
+    /// Symbol of the cell in the terminal. Trees that we cannot see (yet) are dots.
+    fn symbol(self) -> char {
+        match self {
+            Cell::Unknown(_) | Cell::Hidden(_) => '.',
+            Cell::Best(_) => '*',
+            Cell::Visible(h) | Cell::Sight(h) => char::from_digit(h as u32, 10).unwrap_or('?'),
+        }
+    }
+}
+
+impl Paint for Cell {
+    const KINDS: &'static [&'static str] = &["unknown", "visible", "hidden", "best", "sight"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Cell::Unknown(_) => "unknown",
+            Cell::Visible(_) => "visible",
+            Cell::Hidden(_) => "hidden",
+            Cell::Best(_) => "best",
+            Cell::Sight(_) => "sight",
+        }
+    }
+
+    /// Taller trees are brighter.
+    fn color(&self) -> Rgb {
+        let shade = |h: usize, [r, g, b]: Rgb| {
+            let scale = |c: u8| (c as usize * (h + 6) / 15) as u8;
+            [scale(r), scale(g), scale(b)]
+        };
+        match *self {
+            Cell::Unknown(h) => shade(h, [0x80, 0x80, 0x80]),
+            Cell::Visible(h) => shade(h, [0x00, 0xff, 0x00]),
+            Cell::Hidden(h) => shade(h, [0x00, 0x60, 0x30]),
+            Cell::Best(_) => [0xff, 0xff, 0x66],
+            Cell::Sight(h) => shade(h, [0xff, 0x99, 0x00]),
+        }
+    }
+}
+
+/// Animation of the survey of the trees, one row at a time.
+struct Animation {
+    scenario: Grid<usize>,
+    cells: Grid<Cell>,
+    /// Number of rows already checked.
+    n: usize,
+    /// If true, we look for the best tree (second part). Otherwise, we look for visible trees.
+    scenic: bool,
+    /// Best tree so far (and its scenic score).
+    best: Option<(Point, usize)>,
+    /// Trees that can be seen from the best tree (including the best tree).
+    sight: Vec<Point>,
+    /// Number of visible trees so far.
+    n_visible: usize,
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        if self.n == self.scenario.n_rows() {
+            return false;
+        }
+        let y = self.n as i32;
+        for x in 0..self.scenario.n_cols() as i32 {
+            let point = Point::new(x, y);
+            let tree = self.scenario[point];
+            self.cells[point] = match self.scenic {
+                true => {
+                    let score = scenic_score(&self.scenario, point);
+                    if self.best.is_none_or(|(_, best)| score > best) {
+                        self.best = Some((point, score));
+                    }
+                    Cell::Hidden(tree)
+                }
+                false if visible(&self.scenario, point) => {
+                    self.n_visible += 1;
+                    Cell::Visible(tree)
+                }
+                false => Cell::Hidden(tree),
+            };
+        }
+        if let Some((best, _)) = self.best.filter(|_| self.scenic) {
+            // We only show the sight lines of the best tree, so we reset the previous ones
+            for point in std::mem::take(&mut self.sight) {
+                let h = self.scenario[point];
+                self.cells[point] = match point.y <= y {
+                    true => Cell::Hidden(h),
+                    false => Cell::Unknown(h),
+                };
+            }
+            for d in Direction::ALL {
+                let n = viewing_distance(&self.scenario, best, d);
+                self.sight.extend(self.scenario.ray(best, d).take(n));
+            }
+            for &point in self.sight.iter() {
+                self.cells[point] = Cell::Sight(self.scenario[point]);
+            }
+            self.cells[best] = Cell::Best(self.scenario[best]);
+            self.sight.push(best);
+        }
+        self.n += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.cells.render(|cell| cell.symbol())
+    }
+
+    fn status(&self) -> String {
+        match (self.scenic, self.best) {
+            (false, _) => format!("row {}, {} visible trees", self.n, self.n_visible),
+            (true, None) => format!("row {}", self.n),
+            (true, Some((point, score))) => {
+                format!("row {}, best tree at {} (score {})", self.n, point, score)
+            }
+        }
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        Image::from_grid(&self.cells, palette)
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Cell::KINDS
+    }
+}
+
+/// Solution of the eighth day.
+#[derive(Debug, Clone)]
+pub struct Day08 {
+    /// Height of every tree.
+    scenario: Grid<usize>,
+}
+
+impl Day08 {
+    /// Animation of the survey of the trees of a given part of the puzzle.
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        Box::new(Animation {
+            scenario: self.scenario.clone(),
+            cells: self.scenario.map(|&h| Cell::Unknown(h)),
+            n: 0,
+            scenic: part == 2,
+            best: None,
+            sight: Vec::new(),
+            n_visible: 0,
+        })
+    }
+}
+
+impl Solution for Day08 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Number of trees that are visible from outside the grid.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&self.scenario))
+    }
+
+    /// Highest scenic score of any tree.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(exercise_2(&self.scenario))
+    }
+}
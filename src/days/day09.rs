@@ -0,0 +1,263 @@
+//! Day 9: Rope Bridge.
+
+use crate::error::{column_of, parse_token};
+use crate::export::{Image, Paint, Palette, Render, Rgb, BACKGROUND};
+use crate::geom::{Direction, Point};
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+
+/// Scenario.
+#[derive(Debug)]
+struct Scenario {
+    /// position of the rope knots.
+    knots: Vec<Point>,
+    /// unique points where the tail of the rope has been.
+    tail: HashSet<Point>,
+}
+
+impl Scenario {
+    /// Creates a new scenario with `n_knots` knots.
+    fn new(n_knots: usize) -> Self {
+        let mut tail = HashSet::new();
+        tail.insert(Point::default()); // By default, the tail starts in the point (0, 0)
+        Self {
+            knots: vec![Point::default(); n_knots], // Initially, all the knots are in (0, 0)
+            tail,
+        }
+    }
+
+    /// It returns the number of knots in the rope.
+    fn n_knots(&self) -> usize {
+        self.knots.len()
+    }
+
+    /// It moves the head of the rope to a given `direction`.
+    /// It also computes the new location of the rest of the knots
+    fn step(&mut self, direction: Point) {
+        // First, the head of the rope moves to the desired direction.
+        self.knots[0] += direction;
+        // Next, we update the rest of the knots.
+        for i in 1..self.n_knots() {
+            let (prev_knot, knot) = (self.knots[i - 1], self.knots[i]);
+            // Knots only move when they are no longer touching the previous knot.
+            if prev_knot.chebyshev(knot) > 1 {
+                // If so, they move one step (diagonals included) towards the previous knot.
+                self.knots[i] += (prev_knot - knot).signum();
+            }
+        }
+        // Finally, we add the new position of the tail of the rope to the tail history.
+        let tail = *self.knots.last().unwrap();
+        self.tail.insert(tail);
+    }
+}
+
+/// Reads the input and returns a vector with all the movements of the head of the rope.
+fn read_input(input: &str) -> Result<Vec<Point>, AocError> {
+    let mut res = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let chars: Vec<&str> = line.split_whitespace().collect();
+        let (direction, steps) = match chars.as_slice() {
+            [direction, steps] => (*direction, *steps),
+            _ => {
+                return Err(AocError::parse(
+                    i + 1,
+                    1,
+                    "expected a direction and a number of steps",
+                ))
+            }
+        };
+        let vector = direction.parse().ok().and_then(Direction::from_letter);
+        let vector = vector.map(Direction::vector).ok_or_else(|| {
+            AocError::parse(
+                i + 1,
+                column_of(line, direction),
+                format!("unknown direction {:?}", direction),
+            )
+        })?;
+        for _ in 0..parse_token::<usize>(i + 1, line, steps)? {
+            res.push(vector);
+        }
+    }
+    Ok(res)
+}
+
+/// Simulates the scenario
+fn simulate(scenario: &mut Scenario, directions: &[Point]) -> usize {
+    for direction in directions {
+        // We move the rope as much as required by the provided directions
+        scenario.step(*direction);
+    }
+    // Finally, we return the length of the tail history.
+    scenario.tail.len()
+}
+
+/// Size of the window of the animation. The window follows the head of the rope.
+const WINDOW: Point = Point::new(60, 24);
+
+/// Animation of the rope, one step of the head at a time.
+struct Animation {
+    scenario: Scenario,
+    moves: Vec<Point>,
+    /// Number of moves already done.
+    n: usize,
+}
+
+/// What we see in every point of the animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Head,
+    Tail,
+    /// Knot in the middle of the rope (with its index).
+    Knot(usize),
+    Start,
+    Visited,
+    Empty,
+}
+
+impl Cell {
+    /// Symbol of the cell in the terminal.
+    fn symbol(self) -> char {
+        match self {
+            Cell::Head => 'H',
+            Cell::Tail => 'T',
+            Cell::Knot(i) => char::from_digit(i as u32, 36).unwrap_or('?'),
+            Cell::Start => 's',
+            Cell::Visited => '#',
+            Cell::Empty => '.',
+        }
+    }
+}
+
+impl Paint for Cell {
+    const KINDS: &'static [&'static str] = &["head", "knot", "start", "visited", "empty"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Cell::Head => "head",
+            Cell::Tail | Cell::Knot(_) => "knot",
+            Cell::Start => "start",
+            Cell::Visited => "visited",
+            Cell::Empty => "empty",
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Head => [0xff, 0x00, 0x00],
+            Cell::Tail | Cell::Knot(_) => [0xff, 0xff, 0x66],
+            Cell::Start => [0x00, 0xcc, 0x00],
+            Cell::Visited => [0x66, 0x66, 0x99],
+            Cell::Empty => BACKGROUND,
+        }
+    }
+}
+
+impl Animation {
+    /// It returns the cell of a point: knots first, then the start, and then the tail history.
+    fn cell(&self, point: Point) -> Cell {
+        let n_knots = self.scenario.n_knots();
+        match self.scenario.knots.iter().position(|&k| k == point) {
+            Some(0) => Cell::Head,
+            Some(i) if n_knots == 2 && i == 1 => Cell::Tail,
+            Some(i) => Cell::Knot(i),
+            None if point == Point::default() => Cell::Start,
+            None if self.scenario.tail.contains(&point) => Cell::Visited,
+            None => Cell::Empty,
+        }
+    }
+
+    /// Upper-left corner of the window.
+    fn corner(&self) -> Point {
+        self.scenario.knots[0] - WINDOW / 2
+    }
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        match self.moves.get(self.n) {
+            Some(&direction) => {
+                self.scenario.step(direction);
+                self.n += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn frame(&self) -> String {
+        let corner = self.corner();
+        let mut res = String::new();
+        for y in corner.y..corner.y + WINDOW.y {
+            for x in corner.x..corner.x + WINDOW.x {
+                res.push(self.cell(Point::new(x, y)).symbol());
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "move {}/{}, the tail visited {} positions",
+            self.n,
+            self.moves.len(),
+            self.scenario.tail.len()
+        )
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        let (width, height) = (WINDOW.x as usize, WINDOW.y as usize);
+        Image::from_fn(self.corner(), width, height, palette, |p| self.cell(p))
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Cell::KINDS
+    }
+}
+
+/// Solution of the ninth day.
+#[derive(Debug, Clone)]
+pub struct Day09 {
+    /// Movements of the head of the rope, one step at a time.
+    moves: Vec<Point>,
+}
+
+impl Day09 {
+    /// Animation of the rope of a given part of the puzzle.
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        let n_knots = match part {
+            1 => 2,
+            _ => 10,
+        };
+        Box::new(Animation {
+            scenario: Scenario::new(n_knots),
+            moves: self.moves.clone(),
+            n: 0,
+        })
+    }
+}
+
+impl Solution for Day09 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            moves: read_input(input)?,
+        })
+    }
+
+    /// Positions visited by the tail of a rope with 2 knots.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(simulate(&mut Scenario::new(2), &self.moves))
+    }
+
+    /// Positions visited by the tail of a rope with 10 knots.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(simulate(&mut Scenario::new(10), &self.moves))
+    }
+}
@@ -0,0 +1,115 @@
+//! Day 10: Cathode-Ray Tube.
+
+use crate::error::{column_of, parse_token};
+use crate::{AocError, Solution};
+
+/// Reads the input and returns a vector with the changes in x for each cycle.
+fn read_input(input: &str) -> Result<Vec<i32>, AocError> {
+    let mut res = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let chars: Vec<&str> = line.split_whitespace().collect();
+        match chars.as_slice() {
+            // this represents a noop operation
+            ["noop"] => res.push(0),
+            ["addx", value] => {
+                // For addx operations, we add a 0 for the first cycle and then the value of the instruction!
+                res.push(0);
+                res.push(parse_token(i + 1, line, value)?);
+            }
+            [] => continue,
+            [op, ..] => {
+                return Err(AocError::parse(
+                    i + 1,
+                    column_of(line, op),
+                    format!("unknown instruction {:?}", line.trim()),
+                ))
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// It checks that the program lasts at least `n_cycles` cycles.
+fn check_cycles(x_history: &[i32], n_cycles: usize) -> Result<(), AocError> {
+    match x_history.len() >= n_cycles {
+        true => Ok(()),
+        false => Err(AocError::no_solution(format!(
+            "the program ends before cycle {}",
+            n_cycles
+        ))),
+    }
+}
+
+/// From a set of operations, it returns a vector with the history of the values of the x register.
+/// The value of x AT THE END of the ith cycle is the ith index of the resulting vector.
+fn compute_x(ops: &[i32]) -> Vec<i32> {
+    let mut x = 1; // initially, x is set to 1
+    let mut x_history = vec![x]; // we add the initial value of x to its history
+    for op in ops {
+        x += *op;
+        x_history.push(x);
+    }
+    x_history
+}
+
+/// It computes the signal strength as requested in the first exercise.
+fn exercise_1(x_history: &[i32]) -> i32 {
+    let mut res = 0;
+    for i in (20..220 + 1).step_by(40) {
+        res += i as i32 * x_history[i - 1];
+    }
+    res
+}
+
+/// It represents the display from the values of x.
+fn exercise_2(x_history: &[i32]) -> String {
+    let mut res = String::new();
+    // We iterate over the 240 cycles required to print the display.
+    for cycle in 1..240 + 1 {
+        let sprite = x_history[cycle - 1]; // center of the sprite in the current cycle
+        let column = (cycle as i32 - 1) % 40; // column index in the current cycle
+        if sprite >= column - 1 && sprite <= column + 1 {
+            // If the column is in the same location the pixels of the sprite, we print a '#'
+            res.push('#');
+        } else {
+            // Otherwise, we print a ' ' (the output is prettier than using '.', IMO)
+            res.push(' ');
+        }
+        if column == 40 - 1 {
+            // If we reach the last column, we print a new line
+            res.push('\n');
+        }
+    }
+    res
+}
+
+/// Solution of the tenth day.
+#[derive(Debug, Clone)]
+pub struct Day10 {
+    /// Value of the x register at the end of every cycle.
+    x_history: Vec<i32>,
+}
+
+impl Solution for Day10 {
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            x_history: compute_x(&read_input(input)?),
+        })
+    }
+
+    /// Sum of the signal strengths during the interesting cycles.
+    fn part1(&self) -> Result<i32, AocError> {
+        check_cycles(&self.x_history, 220)?;
+        Ok(exercise_1(&self.x_history))
+    }
+
+    /// Image rendered on the CRT.
+    fn part2(&self) -> Result<String, AocError> {
+        check_cycles(&self.x_history, 240)?;
+        Ok(exercise_2(&self.x_history))
+    }
+}
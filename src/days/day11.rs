@@ -1,5 +1,11 @@
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
+//! Day 11: Monkey in the Middle.
+
+use crate::diag::diag;
+use crate::parser::{
+    blocks, complete, either, field, pair, preceded, separated, spaces, tag, terminated, unsigned,
+    Input, PResult, Parser,
+};
+use crate::{AocError, Solution};
 
 /// Auxiliary structure for worry operations used by the monkeys
 #[derive(Debug, Clone, Copy)]
@@ -43,7 +49,7 @@ impl Monkey {
     /// It returns a vector of pairs (worry level of object, ID of the monkey receiving the object).
     fn turn(&mut self, relaxed: bool) -> Vec<(usize, usize)> {
         // n times increments by the number of objects that the monkey is holding.
-        self.n_times += self.objects.len() as usize;
+        self.n_times += self.objects.len();
         let mut res = Vec::new();
         for old_worry in self.objects.iter() {
             // We update the worry level associated to each object.
@@ -53,7 +59,7 @@ impl Monkey {
                 new_worry /= 3;
             }
             // We determine which monkey will receive the object and push it to the result vector
-            let monkey = match new_worry % self.divisible == 0 {
+            let monkey = match new_worry.is_multiple_of(self.divisible) {
                 true => self.if_true,
                 false => self.if_false,
             };
@@ -113,9 +119,11 @@ impl Scenario {
             self.round(lcm);
             // We log some results from time to time
             if i == 1 || i <= 20 && i % 5 == 0 || i % 1000 == 0 {
-                println!("== After round {i} ==");
-                self.monkey_business();
-                println!();
+                diag!("== After round {i} ==");
+                for (j, monkey) in self.monkeys.iter().enumerate() {
+                    diag!("Monkey {} inspected items {} times", j, monkey.n_times);
+                }
+                diag!();
             }
         }
     }
@@ -124,8 +132,7 @@ impl Scenario {
     fn monkey_business(&self) -> usize {
         // We just have to find the two highest n_times within all the monkeys.
         let (mut first, mut second) = (0, 0);
-        for (i, monkey) in self.monkeys.iter().enumerate() {
-            println!("Monkey {} inspected items {} times", i, monkey.n_times);
+        for monkey in self.monkeys.iter() {
             if monkey.n_times > first {
                 second = first;
                 first = monkey.n_times;
@@ -137,72 +144,103 @@ impl Scenario {
     }
 }
 
-/// Reads the input file and returns a scenario with the initial configuration of the monkeys.
-fn read_input(path: &str) -> Scenario {
+/// It parses the operation of a monkey (e.g., `* 19`).
+fn operation(input: Input<'_>) -> PResult<'_, Operation> {
+    let operand = |op| preceded(pair(tag(op), spaces()), unsigned());
+    let exponential =
+        preceded(pair(tag("*"), spaces()), tag("old")).map(|_| Operation::Exponential);
+    let add = operand("+").map(Operation::Add);
+    let multiply = operand("*").map(Operation::Multiply);
+    either(exponential, either(add, multiply))
+        .label("an operation")
+        .parse(input)
+}
+
+/// It parses the ID of the monkey that receives an object, together with its line and column.
+/// We only know which monkeys exist after parsing all of them, so we check it later.
+fn target(input: Input<'_>) -> PResult<'_, (usize, (usize, usize))> {
+    let (id, rest) = unsigned().parse(input)?;
+    Ok(((id, input.location()), rest))
+}
+
+/// It parses the record of a monkey and the locations of the monkeys that receive its objects.
+fn monkey(input: Input<'_>) -> PResult<'_, (Monkey, [(usize, usize); 2])> {
+    let (_, input) = field("Monkey", terminated(unsigned::<usize>(), tag(":"))).parse(input)?;
+    // First, we parse the monkey objects
+    let items = separated(unsigned(), pair(tag(","), spaces()));
+    let (objects, input) = field("Starting items:", items).parse(input)?;
+    // Next, we parse the operation
+    let (operation, input) = field("Operation: new = old", operation).parse(input)?;
+    // Next, we parse the division factor
+    let divisible = unsigned().try_map(|d| match d {
+        0 => Err("cannot divide by 0"),
+        d => Ok(d),
+    });
+    let (divisible, input) = field("Test: divisible by", divisible).parse(input)?;
+    // Finally, the if_true and if_false monkeys
+    let (if_true, input) = field("If true: throw to monkey", target).parse(input)?;
+    let (if_false, input) = field("If false: throw to monkey", target).parse(input)?;
+    let monkey = Monkey {
+        objects,
+        operation,
+        divisible,
+        if_true: if_true.0,
+        if_false: if_false.0,
+        n_times: 0,
+    };
+    Ok(((monkey, [if_true.1, if_false.1]), input))
+}
+
+/// Reads the input and returns a scenario with the initial configuration of the monkeys.
+/// Monkeys are separated by blank lines.
+fn read_input(input: &str) -> Result<Scenario, AocError> {
+    let records = complete(&blocks(monkey), input)?;
+    // Monkeys can only throw objects to monkeys of the scenario
+    let n_monkeys = records.len();
     let mut scenario = Scenario::new();
-    let file = File::open(path).expect("input file not found");
-    let reader = BufReader::new(file);
-    let lines = reader.lines();
-
-    let x: Vec<String> = lines.map(|l| l.expect("error parsing line")).collect();
-    for i in (0..x.len()).step_by(7) {
-        // First, we parse the monkey objects
-        let aux: Vec<&str> = x[i + 1].split(&[':', ',']).collect();
-        let objects: Vec<usize> = aux
-            .iter()
-            .map(|s| (*s).trim().parse::<usize>())
-            .filter(|c| c.is_ok())
-            .map(|c| c.expect(""))
-            .collect();
-        // Next, we parse the operation
-        let aux: Vec<&str> = x[i + 2].split_whitespace().collect();
-        let operation = match aux[4] {
-            "+" => Operation::Add(aux[5].parse::<usize>().expect("error parsing addition")),
-            "*" => match aux[5] {
-                "old" => Operation::Exponential,
-                _ => Operation::Multiply(
-                    aux[5]
-                        .parse::<usize>()
-                        .expect("error parsing multiplication"),
-                ),
-            },
-            _ => panic!("unknown operation"),
-        };
-        // Next, we parse the division factor
-        let aux: Vec<&str> = x[i + 3].split_whitespace().collect();
-        let divisible = aux[3].parse::<usize>().expect("error parsing divisible");
-        // Next, the if_true monkey
-        let aux: Vec<&str> = x[i + 4].split_whitespace().collect();
-        let if_true = aux[5]
-            .parse::<usize>()
-            .expect("error parsing if_true monkey");
-        // Finally, the if_false monkey
-        let aux: Vec<&str> = x[i + 5].split_whitespace().collect();
-        let if_false = aux[5]
-            .parse::<usize>()
-            .expect("error parsing if_true monkey");
-        // We add a new monkey to the scenario
-        scenario.add_monkey(Monkey {
-            objects,
-            operation,
-            divisible,
-            if_true,
-            if_false,
-            n_times: 0,
-        })
+    for (monkey, locations) in records {
+        for (id, (line, column)) in [monkey.if_true, monkey.if_false].into_iter().zip(locations) {
+            if id >= n_monkeys {
+                return Err(AocError::parse(
+                    line,
+                    column,
+                    format!("monkey {} does not exist", id),
+                ));
+            }
+        }
+        scenario.add_monkey(monkey);
     }
-    scenario
+    Ok(scenario)
 }
 
-fn main() {
-    // First we read the input file.
-    let mut scenario_1 = read_input("data/11_input.txt");
-    let mut scenario_2 = scenario_1.clone();
-    // Exercise 1: I'm relaxed
-    scenario_1.rounds(20, true);
-    println!("Monkey business: {}", scenario_1.monkey_business());
-    println!();
-    // Exercise 2: I'm NOT relaxed
-    scenario_2.rounds(10000, false);
-    println!("Monkey business: {}", scenario_2.monkey_business());
+/// Solution of the eleventh day.
+#[derive(Debug, Clone)]
+pub struct Day11 {
+    /// Initial configuration of the monkeys.
+    scenario: Scenario,
+}
+
+impl Solution for Day11 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Monkey business after 20 rounds (I'm relaxed).
+    fn part1(&self) -> Result<usize, AocError> {
+        let mut scenario = self.scenario.clone();
+        scenario.rounds(20, true);
+        Ok(scenario.monkey_business())
+    }
+
+    /// Monkey business after 10000 rounds (I'm NOT relaxed).
+    fn part2(&self) -> Result<usize, AocError> {
+        let mut scenario = self.scenario.clone();
+        scenario.rounds(10000, false);
+        Ok(scenario.monkey_business())
+    }
 }
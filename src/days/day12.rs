@@ -0,0 +1,263 @@
+//! Day 12: Hill Climbing Algorithm.
+
+use crate::export::{Image, Paint, Palette, Render, Rgb};
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::search::a_star;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+
+#[derive(Debug, Clone)]
+/// My simulation scenario.
+struct Scenario {
+    /// Pixel map of the place. The height is represented with usize numbers.
+    map: Grid<usize>,
+    /// Coordinates of the starting point.
+    start: Point,
+    /// Coordinates of the exit point.
+    exit: Point,
+}
+
+impl Scenario {
+    /// It computes the distance between two cells.
+    /// If you cannot move from `from` to `to`, it returns `None`.
+    /// If `from` and `to` are the same, it returns `Some(0)`.
+    /// Otherwise, it returns `Some(1)` (you only need to move one step).
+    fn distance(&self, from: Point, to: Point) -> Option<usize> {
+        // First we check that the cells are part of the map
+        if !self.map.contains(from) || !self.map.contains(to) {
+            return None;
+        }
+        match from.manhattan(to) {
+            0 => Some(0), // It is the same cell! distance is 0
+            1 => {
+                // Cells are adjacent, let's see the heights
+                let (from, to) = (self.map[from], self.map[to]);
+                // Destination cell must be 1 step higher or less!
+                match from >= to || to - from < 2 {
+                    true => Some(1),
+                    false => None,
+                }
+            }
+            _ => None, // Cells are not adjacent, distance does not apply
+        }
+    }
+
+    /// A* algorithm for moving from any of the `sources` to the exit.
+    /// If you can reach the exit, it returns a vector with the best path.
+    /// Otherwise, it return `None`.
+    fn a_star(&self, sources: impl IntoIterator<Item = Point>) -> Option<Vec<Point>> {
+        let search = a_star(
+            sources,
+            |&node| self.map.neighbours4(node),
+            |&from, &to| self.distance(from, to),
+            // We need at least one step per cell, so the Manhattan distance never overestimates
+            |&node| node.manhattan(self.exit) as usize,
+            |&node| node == self.exit,
+        );
+        search.path(search.target()?)
+    }
+}
+
+/// It returns the only point of the map with a given mark.
+fn find_mark(map: &Grid<char>, mark: char, name: &str) -> Result<Point, AocError> {
+    let mut points = map.positions(|&c| c == mark);
+    let point = points
+        .next()
+        .ok_or_else(|| AocError::unexpected_end(map.n_rows(), name))?;
+    match points.next() {
+        Some(p) => Err(AocError::parse(
+            p.y as usize + 1,
+            p.x as usize + 1,
+            format!("duplicated {:?}", mark),
+        )),
+        None => Ok(point),
+    }
+}
+
+/// Reads the input and returns a scenario.
+/// The map must be a rectangle with exactly one starting point and one exit point.
+fn read_input(input: &str) -> Result<Scenario, AocError> {
+    let map = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Some(c),
+        _ => None,
+    })?;
+    let start = find_mark(&map, 'S', "the starting point 'S'")?;
+    let exit = find_mark(&map, 'E', "the exit point 'E'")?;
+    let map = map.map(|&c| match c {
+        'S' => 0,                           // Starting point (height is 'a')
+        'E' => 'z' as usize - 'a' as usize, // Exit point (height is 'z')
+        _ => c as usize - 'a' as usize,     // lowercase imply a height
+    });
+    Ok(Scenario { map, start, exit })
+}
+
+/// What we see in every cell of the animation (with the height of the hills).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Hill(usize),
+    Path(usize),
+    Start,
+    Exit,
+}
+
+impl Cell {
+    /// Symbol of the cell in the terminal.
+    fn symbol(self) -> char {
+        match self {
+            Cell::Hill(h) => (b'a' + h as u8) as char,
+            Cell::Path(_) => '#',
+            Cell::Start => 'S',
+            Cell::Exit => 'E',
+        }
+    }
+}
+
+impl Paint for Cell {
+    const KINDS: &'static [&'static str] = &["hill", "path", "start", "exit"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Cell::Hill(_) => "hill",
+            Cell::Path(_) => "path",
+            Cell::Start => "start",
+            Cell::Exit => "exit",
+        }
+    }
+
+    /// Higher hills are brighter.
+    fn color(&self) -> Rgb {
+        match *self {
+            Cell::Hill(h) => {
+                let c = 0x20 + (h * 0xc0 / 25) as u8;
+                [c / 2, c, c / 2]
+            }
+            Cell::Path(h) => [0xff, 0x99 + (h * 0x66 / 25) as u8, 0x00],
+            Cell::Start => [0x00, 0x99, 0xff],
+            Cell::Exit => [0xff, 0x00, 0x00],
+        }
+    }
+}
+
+/// Animation of the best path, one step at a time.
+struct Animation {
+    scenario: Scenario,
+    /// Best path to the exit (empty if there is no path).
+    path: Vec<Point>,
+    /// Number of steps already taken.
+    n: usize,
+}
+
+impl Animation {
+    /// It returns the cell of a point. We draw the steps already taken over the hills.
+    fn cell(&self, point: Point) -> Cell {
+        let taken = &self.path[..self.path.len().min(self.n + 1)];
+        match point {
+            p if p == self.scenario.exit => Cell::Exit,
+            p if self.path.first() == Some(&p) => Cell::Start,
+            p if taken.contains(&p) => Cell::Path(self.scenario.map[p]),
+            p => Cell::Hill(self.scenario.map[p]),
+        }
+    }
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        match self.n + 1 < self.path.len() {
+            true => {
+                self.n += 1;
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn frame(&self) -> String {
+        let cells = self.scenario.map.iter().map(|(p, _)| (p, self.cell(p)));
+        let mut res = String::new();
+        for (point, cell) in cells {
+            if point.x == 0 && point.y > 0 {
+                res.push('\n');
+            }
+            res.push(cell.symbol());
+        }
+        res.push('\n');
+        res
+    }
+
+    fn status(&self) -> String {
+        match self.path.is_empty() {
+            true => "there is no path to the exit".to_string(),
+            false => format!("step {}/{}", self.n, self.path.len() - 1),
+        }
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        let map = &self.scenario.map;
+        Image::from_fn(Point::default(), map.n_cols(), map.n_rows(), palette, |p| {
+            self.cell(p)
+        })
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Cell::KINDS
+    }
+}
+
+/// Solution of the twelfth day.
+#[derive(Debug, Clone)]
+pub struct Day12 {
+    /// Height map with the starting and exit points.
+    scenario: Scenario,
+}
+
+impl Day12 {
+    /// Animation of the best path of a given part of the puzzle.
+    /// The path of the second part starts from the best square with elevation 'a'.
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        let path = match part {
+            1 => self.scenario.a_star([self.scenario.start]),
+            _ => self
+                .scenario
+                .a_star(self.scenario.map.positions(|&h| h == 0)),
+        };
+        Box::new(Animation {
+            scenario: self.scenario.clone(),
+            path: path.unwrap_or_default(),
+            n: 0,
+        })
+    }
+}
+
+impl Solution for Day12 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Fewest steps from the starting point to the exit.
+    fn part1(&self) -> Result<usize, AocError> {
+        let best = self
+            .scenario
+            .a_star([self.scenario.start])
+            .ok_or_else(|| AocError::no_solution("there is no path to the exit"))?;
+        Ok(best.len() - 1)
+    }
+
+    /// Fewest steps from any square with elevation 'a' to the exit.
+    fn part2(&self) -> Result<usize, AocError> {
+        // We start from every cell with a height of 'a' at the same time
+        let origins = self.scenario.map.positions(|&height| height == 0);
+        let best = self
+            .scenario
+            .a_star(origins)
+            .ok_or_else(|| AocError::no_solution("there is no hiking path to the exit"))?;
+        Ok(best.len() - 1)
+    }
+}
@@ -0,0 +1,147 @@
+//! Day 13: Distress Signal.
+
+use crate::parser::{
+    blocks, complete, delimited, either, line, pair, separated, tag, unsigned, Input, PResult,
+    Parser,
+};
+use crate::{AocError, Solution};
+use std::cmp::{Ord, Ordering};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+/// An element can either be a number or a list of elements.
+/// Elements are displayed just like they appear in the input (e.g., `[1,[2,3]]`).
+pub enum Element {
+    Num(usize),
+    List(Vec<Element>),
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Element::Num(n) => {
+                write!(f, "{}", n)
+            }
+            Element::List(l) => {
+                write!(f, "[")?;
+                for (i, element) in l.iter().enumerate() {
+                    match i {
+                        0 => write!(f, "{}", element)?,
+                        _ => write!(f, ",{}", element)?,
+                    }
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl Ord for Element {
+    /// Comparison as requested in the exercise.
+    /// Note that a number and a list with only that number are equal, even if they are not `==`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            Element::Num(x) => match other {
+                Element::Num(y) => x.cmp(y),
+                Element::List(_) => Element::List(vec![Element::Num(*x)]).cmp(other),
+            },
+            Element::List(v) => match other {
+                Element::Num(y) => self.cmp(&Element::List(vec![Element::Num(*y)])),
+                Element::List(w) => v.cmp(w),
+            },
+        }
+    }
+}
+
+impl PartialOrd<Self> for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// It parses an element: a number or a list of elements separated by commas within brackets.
+fn element(input: Input<'_>) -> PResult<'_, Element> {
+    let list = delimited(tag("["), separated(element, tag(",")), tag("]"));
+    either(unsigned().map(Element::Num), list.map(Element::List))
+        .label("a number or a list")
+        .parse(input)
+}
+
+impl FromStr for Element {
+    type Err = AocError;
+
+    /// This was the toughest part: parsing the file!
+    /// Now the parser combinators do the heavy lifting and tell you where the error is.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        complete(&element, s)
+    }
+}
+
+/// Reads the input and returns a scenario.
+/// Pairs of packets are separated by blank lines.
+fn read_input(input: &str) -> Result<Vec<(Element, Element)>, AocError> {
+    let packet = || line(element);
+    complete(
+        &blocks(pair(packet(), packet().label("the pair of the packet"))),
+        input,
+    )
+}
+
+fn exercise_1(scenario: &[(Element, Element)]) -> usize {
+    let mut res = 0;
+    for (i, (first, second)) in scenario.iter().enumerate() {
+        if first <= second {
+            res += i + 1;
+        }
+    }
+    res
+}
+
+fn exercise_2(scenario: &[(Element, Element)]) -> usize {
+    let mut packets = Vec::new();
+    let packet_2 = Element::List(vec![Element::List(vec![Element::Num(2)])]);
+    let packet_6 = Element::List(vec![Element::List(vec![Element::Num(6)])]);
+    packets.push(packet_2.clone());
+    packets.push(packet_6.clone());
+    for (first, second) in scenario.iter() {
+        packets.push(first.clone());
+        packets.push(second.clone());
+    }
+    packets.sort();
+    let mut res = 1;
+    for (i, packet) in packets.iter().enumerate() {
+        if *packet == packet_2 || *packet == packet_6 {
+            res *= i + 1;
+        }
+    }
+    res
+}
+
+/// Solution of the thirteenth day.
+#[derive(Debug, Clone)]
+pub struct Day13 {
+    /// Pairs of packets.
+    pairs: Vec<(Element, Element)>,
+}
+
+impl Solution for Day13 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            pairs: read_input(input)?,
+        })
+    }
+
+    /// Sum of the indices of the pairs that are in the right order.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&self.pairs))
+    }
+
+    /// Decoder key for the distress signal.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(exercise_2(&self.pairs))
+    }
+}
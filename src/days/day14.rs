@@ -0,0 +1,317 @@
+//! Day 14: Regolith Reservoir.
+
+use crate::error::{column_of, parse_token};
+use crate::export::{Image, Paint, Palette, Render, Rgb, BACKGROUND};
+use crate::geom::Point;
+use crate::sparse::ChunkedGrid;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+use std::cmp;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// It parses a point with the format `x,y`.
+/// Errors are reported in line 1, and columns are relative to the string.
+fn read_point(s: &str) -> Result<Point, AocError> {
+    let coordinates: Vec<&str> = s.split(',').map(|x| x.trim()).collect();
+    let (x, y) = match coordinates.as_slice() {
+        [x, y] => (*x, *y),
+        _ => {
+            return Err(AocError::parse(
+                1,
+                column_of(s, s.trim_start()),
+                "points must have exactly two coordinates",
+            ))
+        }
+    };
+    // Coordinates are used as indices of the map, so they cannot be negative
+    let (x, y) = (parse_token::<u16>(1, s, x)?, parse_token::<u16>(1, s, y)?);
+    Ok(Point::new(x as i32, y as i32))
+}
+
+/// Helper struct to parse the input file
+#[derive(Debug, Clone)]
+struct Rock {
+    points: Vec<Point>,
+}
+
+impl FromStr for Rock {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut points: Vec<Point> = Vec::new();
+        for point in s.split("->") {
+            let column = column_of(s, point);
+            let point = read_point(point).map_err(|e| e.offset_columns(column - 1))?;
+            // Paths can only go in horizontal or vertical lines
+            if let Some(prev) = points.last() {
+                if prev.x != point.x && prev.y != point.y {
+                    return Err(AocError::parse(
+                        1,
+                        column,
+                        "rock paths must be straight lines",
+                    ));
+                }
+            }
+            points.push(point);
+        }
+        Ok(Self { points })
+    }
+}
+
+/// All the possible elements in the scenario
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Element {
+    Air,
+    Rock,
+    Sand,
+    Source,
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let x = match self {
+            Element::Air => ' ', // Representing the air as '.' is a little bit confusing
+            Element::Rock => '#',
+            Element::Sand => 'o',
+            Element::Source => '+',
+        };
+        write!(f, "{}", x)
+    }
+}
+
+impl Paint for Element {
+    const KINDS: &'static [&'static str] = &["air", "rock", "sand", "source"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Element::Air => "air",
+            Element::Rock => "rock",
+            Element::Sand => "sand",
+            Element::Source => "source",
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Element::Air => BACKGROUND,
+            Element::Rock => [0x7f, 0x6a, 0x55],
+            Element::Sand => [0xff, 0xff, 0x66],
+            Element::Source => [0xff, 0x00, 0x00],
+        }
+    }
+}
+
+/// The actual simulation scenario
+struct Scenario {
+    map: ChunkedGrid<Element>, // Pixels of the map (we do not store the air)
+    corner: Point,             // lower-right coordinate
+    source: Point,             // Point where the sand source is located
+    floor: bool,               // If true, there is an infinite floor below the rocks
+}
+
+/// Distance between the lowest rock and the floor of the second exercise.
+const FLOOR_DEPTH: i32 = 2;
+
+/// Movements of the sand, sorted by preference (down, down-left, and down-right).
+const FALLS: [Point; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+impl Scenario {
+    fn new(rocks: &[Rock], source: Point, floor: bool) -> Self {
+        // First let's find the corner
+        let mut corner = source;
+        for rock in rocks {
+            for point in rock.points.iter() {
+                corner.x = cmp::max(corner.x, point.x);
+                corner.y = cmp::max(corner.y, point.y);
+            }
+        }
+        // Then, we just have to add the proper rocks
+        let mut map = ChunkedGrid::new();
+        for rock in rocks {
+            for i in 0..rock.points.len() - 1 {
+                let (mut from, to) = (rock.points[i], rock.points[i + 1]);
+                // at least one of the coordinates is 0 (see Rock::from_str)
+                let gradient = (to - from).signum();
+                // We fill the pixels from from to to with rocks
+                map.insert(from, Element::Rock);
+                while from != to {
+                    from += gradient;
+                    map.insert(from, Element::Rock);
+                }
+            }
+        }
+        // Finally, we add the sand source to the map
+        map.insert(source, Element::Source);
+        Self {
+            map,
+            corner,
+            source,
+            floor,
+        }
+    }
+
+    /// It returns the element in a given point. The floor (if any) is made of rocks.
+    fn element(&self, point: Point) -> Element {
+        match self.floor && point.y == self.corner.y + FLOOR_DEPTH {
+            true => Element::Rock,
+            false => self.map.get(point).copied().unwrap_or(Element::Air),
+        }
+    }
+
+    /// It creates a new sand pixel and looks for its resting place
+    fn step(&mut self) -> Point {
+        // Initially, the location is the source. Next we iterate looking for the resting place.
+        // We try to move down, then down-left, and then down-right.
+        // If we do not find air, we cannot move anymore!
+        let mut sand = self.source;
+        while let Some(next_sand) = FALLS
+            .into_iter()
+            .map(|delta| sand + delta)
+            .find(|&p| self.element(p) == Element::Air)
+        {
+            sand = next_sand;
+            if !self.floor && sand.y >= self.corner.y {
+                // Without floor, the sand falls to the abyss once it passes the lowest rock
+                break;
+            }
+        }
+        // we modify the proper pixel and return it
+        self.map.insert(sand, Element::Sand);
+        sand
+    }
+}
+
+impl Display for Scenario {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // We only print the area with something that is not air
+        let map = self.map.render(|e| e.copied().unwrap_or(Element::Air));
+        write!(f, "{}", map)
+    }
+}
+
+/// Reads the input and returns the rock paths of the scenario.
+fn read_input(input: &str) -> Result<Vec<Rock>, AocError> {
+    let mut res = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let rock = Rock::from_str(line).map_err(|e| e.offset_lines(i))?;
+        res.push(rock);
+    }
+    Ok(res)
+}
+
+/// First exercise -> we wait until the sand reaches the last row
+fn exercise_1(scenario: &mut Scenario) -> usize {
+    let mut res = 1;
+    while scenario.step().y < scenario.corner.y {
+        res += 1;
+    }
+    res - 1 // we are already overflowing, we need to subtract one!
+}
+
+/// Second exercise -> the scenario has floor, we wait until the sand reaches the source
+fn exercise_2(scenario: &mut Scenario) -> usize {
+    let mut res = 1;
+    while scenario.step() != scenario.source {
+        res += 1;
+    }
+    res
+}
+
+/// Animation of the sand, one unit at a time.
+struct Animation {
+    scenario: Scenario,
+    /// Units of sand at rest.
+    n: usize,
+    /// If true, no more sand can come to rest.
+    over: bool,
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        if self.over {
+            return false;
+        }
+        let scenario = &mut self.scenario;
+        let sand = scenario.step();
+        if !scenario.floor && sand.y >= scenario.corner.y {
+            // This unit falls to the abyss, so it is not at rest
+            scenario.map.remove(sand);
+            self.over = true;
+        } else {
+            self.n += 1;
+            self.over = sand == scenario.source;
+        }
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.scenario.to_string()
+    }
+
+    fn status(&self) -> String {
+        format!("{} units of sand at rest", self.n)
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        let scenario = &self.scenario;
+        let Some((mut min, mut max)) = scenario.map.bounds() else {
+            return Image::new(0, 0, palette.background());
+        };
+        if scenario.floor {
+            // We also draw the floor below the sand
+            max.y = max.y.max(scenario.corner.y + FLOOR_DEPTH);
+        }
+        // We leave a margin of one cell, so the sand never touches the border of the picture
+        (min, max) = (min - Point::new(1, 1), max + Point::new(1, 1));
+        Image::from_bounds(min, max, palette, |p| scenario.element(p))
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Element::KINDS
+    }
+}
+
+/// Location of the sand source.
+const SOURCE: Point = Point::new(500, 0);
+
+/// Solution of the fourteenth day.
+#[derive(Debug, Clone)]
+pub struct Day14 {
+    /// Rock paths of the scan.
+    rocks: Vec<Rock>,
+}
+
+impl Day14 {
+    /// Animation of the sand of a given part of the puzzle (only the second one has a floor).
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        Box::new(Animation {
+            scenario: Scenario::new(&self.rocks, SOURCE, part == 2),
+            n: 0,
+            over: false,
+        })
+    }
+}
+
+impl Solution for Day14 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            rocks: read_input(input)?,
+        })
+    }
+
+    /// Units of sand that come to rest before sand starts flowing into the abyss.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&mut Scenario::new(&self.rocks, SOURCE, false)))
+    }
+
+    /// Units of sand that come to rest before the source gets blocked.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(exercise_2(&mut Scenario::new(&self.rocks, SOURCE, true)))
+    }
+}
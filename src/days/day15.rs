@@ -0,0 +1,235 @@
+//! Day 15: Beacon Exclusion Zone.
+
+use crate::error::parse_token;
+use crate::geom::Point;
+use crate::par;
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::cmp;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// To compute the tuning frequency of a beacon in a given point
+fn tuning_freq(point: Point) -> i64 {
+    point.x as i64 * 4000000 + point.y as i64
+}
+
+/// A structure for representing a measurement.
+#[derive(Debug)]
+struct Measure {
+    /// Location of the sensor.
+    sensor: Point,
+    /// Location of the beacon nearest to the sensor.
+    beacon: Point,
+}
+
+impl FromStr for Measure {
+    type Err = AocError;
+
+    /// Errors are reported in line 1.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut points: Vec<Point> = Vec::new();
+        let re = Regex::new(r"x=(-?\d+), *y=(-?\d+)").unwrap();
+        for cap in re.captures_iter(s) {
+            // Both groups always participate in the match
+            let (x, y) = (&cap[1], &cap[2]);
+            let (x, y) = (parse_token(1, s, x)?, parse_token(1, s, y)?);
+            points.push(Point { x, y });
+        }
+        match points.as_slice() {
+            [sensor, beacon] => Ok(Measure {
+                sensor: *sensor,
+                beacon: *beacon,
+            }),
+            _ => Err(AocError::parse(
+                1,
+                1,
+                format!(
+                    "expected 2 points (sensor and beacon), found {}",
+                    points.len()
+                ),
+            )),
+        }
+    }
+}
+
+/// Reads the input and returns the list of measurements.
+fn read_input(input: &str) -> Result<Vec<Measure>, AocError> {
+    let mut measures = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        measures.push(Measure::from_str(line).map_err(|e| e.offset_lines(i))?);
+    }
+    Ok(measures)
+}
+
+/// Returns the number of locations where a beacon cannot be located for a given row `y`
+fn exercise_1(scenario: &[Measure], y: i32) -> usize {
+    let mut impossible = HashSet::new(); // we use a hash set to avoid duplicates
+    for measure in scenario {
+        let manhattan = measure.sensor.manhattan(measure.beacon);
+        // First, we check if the range of the measure contains the row under study
+        if measure.sensor.y - manhattan <= y && measure.sensor.y + manhattan >= y {
+            // If so, we iterate over all the potential points in the range of the sensor
+            let max_i = manhattan - (measure.sensor.y - y).abs();
+            for x in (measure.sensor.x - max_i)..(measure.sensor.x + max_i + 1) {
+                let point = Point { x, y };
+                // If the point is not the beacon, we add it to the set of impossibles
+                if point != measure.beacon {
+                    impossible.insert(point);
+                }
+            }
+        }
+    }
+    // Beacons of other sensors may be in the row too, and there are beacons there
+    for measure in scenario {
+        impossible.remove(&measure.beacon);
+    }
+    impossible.len() // We return the length of the resulting hash set.
+}
+
+/// Returns the location in row `y` that no sensor covers (if any)
+fn gap(scenario: &[Measure], y: i32, min_val: i32, max_val: i32) -> Option<Point> {
+    let mut intersections = Vec::new();
+    // For every measure, we look for the intersection with the current row
+    for measure in scenario.iter() {
+        let manhattan = measure.sensor.manhattan(measure.beacon);
+        // First, we check that the sensor is close enough to the row under study
+        if (measure.sensor.y - y).abs() > manhattan {
+            continue;
+        }
+        // If so, we compute the intersection and limit it to our search space.
+        let max_i = manhattan - (measure.sensor.y - y).abs();
+        let lower_x = cmp::max(min_val, measure.sensor.x - max_i);
+        let upper_x = cmp::min(max_val, measure.sensor.x + max_i);
+        // If the intersection happens within our search space, we add it to the intersections
+        if upper_x >= lower_x {
+            intersections.push((lower_x, upper_x));
+        }
+    }
+    // Next we sort the intersection intervals in increasing order, and we look for the first
+    // position that they do not cover
+    intersections.sort();
+    let mut covered = min_val - 1;
+    for (left, right) in intersections {
+        if left > covered + 1 {
+            break; // There is a gap before this intersection! this is the solution
+        }
+        // Intersections may be fully contained in the previous ones, so we keep the right-most
+        covered = cmp::max(covered, right);
+    }
+    match covered < max_val {
+        true => Some(Point { x: covered + 1, y }),
+        false => None,
+    }
+}
+
+/// Returns the tuning frequency of the beacon (if any)
+fn exercise_2(scenario: &[Measure], min_val: i32, max_val: i32) -> Option<i64> {
+    // Rows are independent, so we look for the gap in many rows at the same time.
+    // If there were more than one gap, we would still get the one of the first row.
+    let point = par::find_first(min_val..max_val + 1, |y| gap(scenario, y, min_val, max_val))?;
+    Some(tuning_freq(point))
+}
+
+/// Row under study in the first exercise.
+const ROW: i32 = 2000000;
+/// Upper limit of the search space of the second exercise.
+const MAX_VAL: i32 = 4000000;
+
+/// Solution of the fifteenth day.
+#[derive(Debug)]
+pub struct Day15 {
+    /// Measurements of the sensors.
+    measures: Vec<Measure>,
+    /// Row under study in the first exercise.
+    row: i32,
+    /// Upper limit of the search space of the second exercise.
+    max_val: i32,
+}
+
+impl Day15 {
+    /// It reads the measurements with a different row for the first exercise and a different
+    /// upper limit of the search space for the second one (e.g., the example of the puzzle uses
+    /// row 10 and limit 20).
+    pub fn with_params(input: &str, row: i32, max_val: i32) -> Result<Self, AocError> {
+        Ok(Self {
+            measures: read_input(input)?,
+            row,
+            max_val,
+        })
+    }
+}
+
+impl Solution for Day15 {
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Self::with_params(input, ROW, MAX_VAL)
+    }
+
+    /// Number of positions where a beacon cannot be present in the row under study.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&self.measures, self.row))
+    }
+
+    /// Tuning frequency of the distress beacon.
+    fn part2(&self) -> Result<i64, AocError> {
+        exercise_2(&self.measures, 0, self.max_val)
+            .ok_or_else(|| AocError::no_solution("there is no room for the distress beacon"))
+    }
+}
+
+/// Slow but obviously correct solver, so we can check the fast one (see `tests/differential.rs`).
+/// It checks every position one by one, so it only works with the example of the puzzle
+/// (or other inputs with small search spaces).
+#[derive(Debug)]
+pub struct Reference(Day15);
+
+impl Reference {
+    /// Same as [`Day15::with_params`].
+    pub fn with_params(input: &str, row: i32, max_val: i32) -> Result<Self, AocError> {
+        Ok(Self(Day15::with_params(input, row, max_val)?))
+    }
+
+    /// It returns true if a sensor is closer to `point` than to its beacon.
+    fn covered(&self, point: Point) -> bool {
+        let measures = self.0.measures.iter();
+        measures
+            .map(|m| (m.sensor, m.sensor.manhattan(m.beacon)))
+            .any(|(sensor, distance)| sensor.manhattan(point) <= distance)
+    }
+}
+
+impl Solution for Reference {
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self(Day15::parse(input)?))
+    }
+
+    /// We check every position of the row that any sensor may reach.
+    fn part1(&self) -> Result<usize, AocError> {
+        let measures = &self.0.measures;
+        let reach = |m: &Measure| m.sensor.manhattan(m.beacon);
+        let min_x = measures.iter().map(|m| m.sensor.x - reach(m)).min();
+        let max_x = measures.iter().map(|m| m.sensor.x + reach(m)).max();
+        let (min_x, max_x) = (min_x.unwrap_or(0), max_x.unwrap_or(-1));
+        let beacons: HashSet<Point> = measures.iter().map(|m| m.beacon).collect();
+        Ok((min_x..=max_x)
+            .map(|x| Point { x, y: self.0.row })
+            .filter(|&point| self.covered(point) && !beacons.contains(&point))
+            .count())
+    }
+
+    /// We check every position of the search space, row by row.
+    fn part2(&self) -> Result<i64, AocError> {
+        let max_val = self.0.max_val;
+        (0..=max_val)
+            .flat_map(|y| (0..=max_val).map(move |x| Point { x, y }))
+            .find(|&point| !self.covered(point))
+            .map(tuning_freq)
+            .ok_or_else(|| AocError::no_solution("there is no room for the distress beacon"))
+    }
+}
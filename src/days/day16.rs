@@ -0,0 +1,180 @@
+//! Day 16: Proboscidea Volcanium.
+
+use crate::error::{column_of, parse_token};
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::cmp;
+use std::collections::HashMap;
+
+/// Name of the valve where everybody starts.
+const START: &str = "AA";
+
+/// Struct for representing the scenario
+#[derive(Debug)]
+struct Scenario {
+    /// Flows for each valve.
+    flows: Vec<i32>,
+    /// Neighboring valves (i.e., the edges of the graph)
+    neighbors: Vec<Vec<usize>>,
+    /// Valve where everybody starts.
+    start: usize,
+}
+
+impl Scenario {
+    fn best_path(
+        &self,
+        max_t: i32,      // maximum time before the volcano erupts
+        n_people: usize, // number of people working on leaving pressure
+        valve: usize,    // Current valve
+        opened: usize,   // bit mask indicating which valves are opened
+        t: i32,          // current time limit
+        memo: &mut HashMap<(usize, usize, usize, i32), i32>, // handy cache
+    ) -> i32 {
+        // Break condition -> t is 0...
+        if t == 0 {
+            return match n_people {
+                1 => 0, // ... and there's no people left
+                // Otherwise, we check how the next person would perform
+                n => self.best_path(max_t, n - 1, self.start, opened, max_t, memo),
+            };
+        }
+        let mem_key = (n_people, valve, opened, t);
+        // Break condition: result is already in the cache
+        if memo.contains_key(&mem_key) {
+            return memo[&mem_key];
+        }
+        let mut best = 0;
+        // If this valve is still closed, we explore how good would be that move
+        let valve_closed = opened & (1 << valve) == 0;
+        if valve_closed && self.flows[valve] > 0 {
+            let new_opened = opened | (1 << valve);
+            best = self.flows[valve] * (t - 1)
+                + self.best_path(max_t, n_people, valve, new_opened, t - 1, memo);
+        }
+        // In any case, we also check how wise would be to move to neighboring valves
+        for &other_valve in self.neighbors[valve].iter() {
+            best = cmp::max(
+                best,
+                self.best_path(max_t, n_people, other_valve, opened, t - 1, memo),
+            );
+        }
+        // Finally, we add the result to the cache and return the best result
+        memo.insert(mem_key, best);
+        best
+    }
+}
+
+/// Maximum number of valves. Opened valves are stored in a bit mask.
+const MAX_VALVES: usize = usize::BITS as usize;
+
+/// Reads the input and returns the scenario.
+fn read_input(input: &str) -> Result<Scenario, AocError> {
+    let re = Regex::new(
+        r"^Valve ([A-Z]+) has flow rate=([0-9]+); tunnels? leads? to valves? ([A-Z,\s]+)",
+    )
+    .unwrap();
+
+    let mut names = Vec::new();
+    let mut flows = Vec::new();
+    let mut conns: Vec<(usize, &str, Vec<&str>)> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let cap = re.captures(line).ok_or_else(|| {
+            AocError::parse(
+                i + 1,
+                1,
+                "expected \"Valve XX has flow rate=N; tunnels lead to valves YY, ZZ\"",
+            )
+        })?;
+        // All the groups always participate in the match
+        let (name, flow, tunnels) = (
+            cap.get(1).unwrap(),
+            cap.get(2).unwrap(),
+            cap.get(3).unwrap(),
+        );
+        if names.contains(&name.as_str()) {
+            return Err(AocError::parse(
+                i + 1,
+                name.start() + 1,
+                format!("duplicated valve {:?}", name.as_str()),
+            ));
+        }
+        names.push(name.as_str());
+        flows.push(parse_token(i + 1, line, flow.as_str())?);
+        conns.push((
+            i,
+            line,
+            tunnels.as_str().split(',').map(|s| s.trim()).collect(),
+        ));
+    }
+    if names.len() > MAX_VALVES {
+        return Err(AocError::no_solution(format!(
+            "there are more than {} valves",
+            MAX_VALVES
+        )));
+    }
+    // Next we parse the neighboring valves as if they were indices
+    let mut neighbors: Vec<Vec<usize>> = Vec::new();
+    for (i, line, tunnels) in conns {
+        let mut valves = Vec::new();
+        for tunnel in tunnels {
+            match names.iter().position(|x| *x == tunnel) {
+                Some(valve) => valves.push(valve),
+                None => {
+                    return Err(AocError::parse(
+                        i + 1,
+                        column_of(line, tunnel),
+                        format!("unknown valve {:?}", tunnel),
+                    ))
+                }
+            }
+        }
+        neighbors.push(valves);
+    }
+    let start = names
+        .iter()
+        .position(|x| *x == START)
+        .ok_or_else(|| AocError::no_solution(format!("starting valve {} not found", START)))?;
+    Ok(Scenario {
+        flows,
+        neighbors,
+        start,
+    })
+}
+
+fn exercise_1(scenario: &Scenario, t: i32) -> i32 {
+    let mut memo = HashMap::new();
+    scenario.best_path(t, 1, scenario.start, 0, t, &mut memo)
+}
+
+fn exercise_2(scenario: &Scenario, t: i32) -> i32 {
+    let mut memo = HashMap::new();
+    scenario.best_path(t - 4, 2, scenario.start, 0, t - 4, &mut memo)
+}
+
+/// Solution of the sixteenth day.
+#[derive(Debug)]
+pub struct Day16 {
+    /// Graph of valves and tunnels.
+    scenario: Scenario,
+}
+
+impl Solution for Day16 {
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Most pressure I can release by myself in 30 minutes.
+    fn part1(&self) -> Result<i32, AocError> {
+        Ok(exercise_1(&self.scenario, 30))
+    }
+
+    /// Most pressure I can release with the help of the elephant.
+    fn part2(&self) -> Result<i32, AocError> {
+        Ok(exercise_2(&self.scenario, 30))
+    }
+}
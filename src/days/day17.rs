@@ -0,0 +1,419 @@
+//! Day 17: Pyroclastic Flow.
+
+use crate::cycle::Memo;
+use crate::export::{Image, Paint, Palette, Render, Rgb, BACKGROUND};
+use crate::geom::{Direction, Point};
+use crate::grid::Grid;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+use std::cmp;
+use std::fmt::{Display, Formatter};
+
+/// Structure to represent a rock
+struct Rock {
+    /// The location of a rock is always the upper left corner,
+    location: Point,
+    /// This vector indicates which internal points are rock in case of collision
+    pieces: Vec<Point>,
+}
+
+impl Rock {
+    /// It creates a new rock in a given location.
+    fn new(location: Point, n: usize) -> Rock {
+        match n {
+            0 => Rock {
+                location,
+                pieces: vec![
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(2, 0),
+                    Point::new(3, 0),
+                ],
+            },
+            1 => Rock {
+                location,
+                pieces: vec![
+                    Point::new(1, 0),
+                    Point::new(0, 1),
+                    Point::new(1, 1),
+                    Point::new(2, 1),
+                    Point::new(1, 2),
+                ],
+            },
+            2 => Rock {
+                location,
+                pieces: vec![
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(2, 0),
+                    Point::new(2, 1),
+                    Point::new(2, 2),
+                ],
+            },
+            3 => Rock {
+                location,
+                pieces: vec![
+                    Point::new(0, 0),
+                    Point::new(0, 1),
+                    Point::new(0, 2),
+                    Point::new(0, 3),
+                ],
+            },
+            4 => Rock {
+                location,
+                pieces: vec![
+                    Point::new(0, 0),
+                    Point::new(1, 0),
+                    Point::new(0, 1),
+                    Point::new(1, 1),
+                ],
+            },
+            _ => panic!("unknown rock code"),
+        }
+    }
+
+    fn update_location(&mut self, new_location: Point) {
+        self.location = new_location;
+    }
+
+    /// Returns a vector with the location of every "rock" points depending on its current location
+    fn get_points(&self) -> Vec<Point> {
+        self.pieces.iter().map(|&p| self.location + p).collect()
+    }
+}
+
+/// Width of the chamber.
+const WIDTH: usize = 7;
+
+/// Depth of the top of the tower that we use as its signature. Columns that stay empty for
+/// longer (e.g., when the jets always push to the same side) do not grow the signature forever.
+const SIGNATURE_DEPTH: usize = 128;
+
+struct Scenario {
+    /// Simulated scenario with rocks etc. Free cells are true, and row 0 is the bottom.
+    map: Grid<bool>,
+    /// Vector with the movement vector of the jets.
+    jets: Vec<Point>,
+}
+
+impl Scenario {
+    fn new(jets: Vec<Point>) -> Self {
+        Self {
+            map: Grid::new(0, WIDTH, true),
+            jets,
+        }
+    }
+
+    /// It returns how many lines are blank at the bottom
+    fn n_blank_rows(&self) -> usize {
+        for (i, line) in self.map.rows().rev().enumerate() {
+            if !line.iter().all(|&b| b) {
+                return i;
+            }
+        }
+        0
+    }
+
+    /// The scenario signature is a slice indicating how far is the first obstacle from the top
+    fn get_signature(&self) -> [usize; WIDTH] {
+        // By default, the signature is the number of rows (up to the maximum depth)
+        let depth = cmp::min(self.map.n_rows(), SIGNATURE_DEPTH);
+        let mut res = [depth; WIDTH];
+        // Then, we iterate from top to bottom to find the first obstacle one each column.
+        for (j, row) in self.map.rows().rev().take(depth).enumerate() {
+            for (i, _) in row.iter().enumerate().filter(|(_, &b)| !b) {
+                res[i] = cmp::min(j, res[i]);
+                // If we are done found the first obstacle for all the columns, we are done
+                if res.iter().all(|&b| b < depth) {
+                    return res;
+                }
+            }
+        }
+        res // If we reach this, it means that at least on column is free from top to bottom
+    }
+
+    /// Returns true if all the points are located in a free space
+    fn valid_location(&self, points: &[Point]) -> bool {
+        points.iter().all(|&p| match self.map.get(p) {
+            Some(&free) => free, // Check if there is something already
+            // It may be way up (valid), out of the margins, or under the floor (invalid)
+            None => p.y >= self.map.n_rows() as i32 && (0..WIDTH as i32).contains(&p.x),
+        })
+    }
+
+    /// It drops the `n`th rock, starting with the `t`th jet, until it comes to rest.
+    /// It returns the time after the rock comes to rest.
+    fn drop_rock(&mut self, n: usize, mut t: usize) -> usize {
+        // First we add empty rows (if required)
+        for _ in 0..(3_usize).saturating_sub(self.n_blank_rows()) {
+            self.map.push_row(true);
+        }
+        // We create the corresponding rock
+        let mut prev_location = Point::new(2, self.map.n_rows() as i32);
+        let mut rock = Rock::new(prev_location, n % 5);
+        // The rock moves until we reach an invalid location
+        while self.valid_location(&rock.get_points()) {
+            prev_location = rock.location;
+            // First, we try to move according to the jet
+            rock.update_location(prev_location + self.jets[t % self.jets.len()]);
+            if !self.valid_location(&rock.get_points()) {
+                // Oops! the location is invalid, we go back
+                rock.update_location(prev_location);
+            }
+            // Then, we try to go down
+            prev_location = rock.location;
+            // The tower grows upwards, so falling decreases y (this is not a screen)
+            rock.update_location(prev_location + Point::new(0, -1));
+            t += 1; // At the end, we increase the time
+        }
+        // Finally, we go back to the previous valid location and fill the gap in the map
+        rock.update_location(prev_location);
+        // We add rows if needed...
+        for point in rock.get_points() {
+            while point.y as usize >= self.map.n_rows() {
+                self.map.push_row(true);
+            }
+            self.map[point] = false;
+        }
+        t
+    }
+
+    /// It returns the height of the tower.
+    fn height(&self) -> usize {
+        self.map.n_rows() - self.n_blank_rows()
+    }
+
+    /// It returns the height of the tower after `n_rocks` rocks.
+    /// The tower eventually grows the same every few rocks, so we only simulate until the
+    /// state repeats and then we extrapolate the height.
+    fn how_tall(&mut self, n_rocks: usize) -> usize {
+        let (mut t, mut n) = (0, 0);
+        let mut memo = Memo::new();
+        let mut heights = vec![0];
+        loop {
+            // The state is the top of the tower, the next jet, and the next rock
+            let state = (self.get_signature(), t % self.jets.len(), n % 5);
+            if let Some(cycle) = memo.insert(state) {
+                // We already know the height of every rock of the first lap
+                return cycle
+                    .extrapolate(n_rocks, &heights)
+                    .expect("the tower is not that tall");
+            }
+            if n == n_rocks {
+                return heights[n]; // We were not lucky: there was no cycle before the last rock
+            }
+            t = self.drop_rock(n, t);
+            n += 1;
+            heights.push(self.height());
+        }
+    }
+}
+
+impl Display for Scenario {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.map.rows().rev() {
+            write!(f, "|")?;
+            for val in row.iter().map(|&x| match x {
+                true => ".",
+                false => "#",
+            }) {
+                write!(f, "{}", val)?;
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, "+")?;
+        for _ in 0..WIDTH {
+            write!(f, "-")?;
+        }
+        writeln!(f, "+")
+    }
+}
+
+/// Number of rows of the tower that we show in the animation (the top of the tower).
+const VISIBLE_ROWS: usize = 40;
+
+/// What we see in every cell of the chamber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Free,
+    Rock,
+    Wall,
+    Floor,
+}
+
+impl Paint for Cell {
+    const KINDS: &'static [&'static str] = &["free", "rock", "wall", "floor"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Cell::Free => "free",
+            Cell::Rock => "rock",
+            Cell::Wall => "wall",
+            Cell::Floor => "floor",
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Free => BACKGROUND,
+            Cell::Rock => [0x99, 0x99, 0xcc],
+            Cell::Wall => [0x66, 0x66, 0x66],
+            Cell::Floor => [0xcc, 0xcc, 0xcc],
+        }
+    }
+}
+
+/// Animation of the tower, one rock at a time.
+struct Animation {
+    scenario: Scenario,
+    /// Number of rocks that came to rest.
+    n: usize,
+    /// Time (i.e., number of jets that pushed the rocks).
+    t: usize,
+    /// Number of rocks to drop.
+    n_rocks: usize,
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        if self.n == self.n_rocks {
+            return false;
+        }
+        self.t = self.scenario.drop_rock(self.n, self.t);
+        self.n += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        // Rows are printed from top to bottom, and the rocks fall at the top
+        let tower = self.scenario.to_string();
+        let mut res = String::new();
+        for line in tower.lines().take(VISIBLE_ROWS) {
+            res += line;
+            res.push('\n');
+        }
+        res
+    }
+
+    fn status(&self) -> String {
+        format!("{} rocks, height {}", self.n, self.scenario.height())
+    }
+}
+
+impl Render for Animation {
+    /// Like the frame, the picture shows the top of the tower with the walls of the chamber.
+    fn render(&self, palette: &Palette) -> Image {
+        let map = &self.scenario.map;
+        let top = map.n_rows() as i32 - 1;
+        // x = 0 and x = WIDTH + 1 are the walls, and the rows go from the top of the tower down
+        Image::from_fn(Point::new(0, 0), WIDTH + 2, VISIBLE_ROWS, palette, |p| {
+            let (x, y) = (p.x - 1, top - p.y);
+            match y {
+                -1 => Cell::Floor,
+                y if y < -1 => Cell::Free,
+                _ if x < 0 || x >= WIDTH as i32 => Cell::Wall,
+                _ => match map[Point::new(x, y)] {
+                    true => Cell::Free,
+                    false => Cell::Rock,
+                },
+            }
+        })
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Cell::KINDS
+    }
+}
+
+/// Reads the input and returns the movement vectors of the jets.
+fn read_input(input: &str) -> Result<Vec<Point>, AocError> {
+    let mut res = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        for (j, c) in line.trim_end().chars().enumerate() {
+            match Direction::from_arrow(c) {
+                Some(d @ (Direction::Left | Direction::Right)) => res.push(d.vector()),
+                _ => return Err(AocError::unexpected_char(i + 1, j + 1, c)),
+            }
+        }
+    }
+    match res.is_empty() {
+        true => Err(AocError::unexpected_end(1, "the jet pattern")),
+        false => Ok(res),
+    }
+}
+
+/// Solution of the seventeenth day.
+#[derive(Debug, Clone)]
+pub struct Day17 {
+    /// Movement vectors of the jets.
+    jets: Vec<Point>,
+}
+
+impl Day17 {
+    /// It returns the height of the tower after `n_rocks` rocks.
+    pub fn height(&self, n_rocks: usize) -> usize {
+        Scenario::new(self.jets.clone()).how_tall(n_rocks)
+    }
+
+    /// Animation of the tower of a given part of the puzzle.
+    /// The tower of the second part is too tall, so we never get to see the last rock.
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        Box::new(Animation {
+            scenario: Scenario::new(self.jets.clone()),
+            n: 0,
+            t: 0,
+            n_rocks: match part {
+                1 => 2022,
+                _ => 1000000000000,
+            },
+        })
+    }
+}
+
+impl Solution for Day17 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            jets: read_input(input)?,
+        })
+    }
+
+    /// Height of the tower after 2022 rocks.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self.height(2022))
+    }
+
+    /// Height of the tower after 1000000000000 rocks.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self.height(1000000000000))
+    }
+}
+
+/// Slow but obviously correct solver, so we can check the fast one (see `tests/differential.rs`).
+/// It drops every rock one by one, without looking for cycles.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    /// Movement vectors of the jets.
+    jets: Vec<Point>,
+}
+
+impl Reference {
+    /// It parses the puzzle input.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            jets: read_input(input)?,
+        })
+    }
+
+    /// It returns the height of the tower after `n_rocks` rocks.
+    pub fn height(&self, n_rocks: usize) -> usize {
+        let mut scenario = Scenario::new(self.jets.clone());
+        let mut t = 0;
+        for n in 0..n_rocks {
+            t = scenario.drop_rock(n, t);
+        }
+        scenario.height()
+    }
+}
@@ -1,8 +1,11 @@
+//! Day 18: Boiling Boulders.
+
+use crate::error::parse_token;
+use crate::search::bfs;
+use crate::{AocError, Solution};
 use std::cmp;
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
 use std::ops::{Add, AddAssign, Sub};
 use std::str::FromStr;
 
@@ -41,15 +44,23 @@ impl Sub<Point> for Point {
 }
 
 impl FromStr for Point {
-    type Err = &'static str;
+    type Err = AocError;
 
+    /// Errors are reported in line 1.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let coords: Vec<i32> = s
-            .trim()
-            .split(',')
-            .map(|n| n.parse::<i32>().unwrap())
-            .collect();
-        Ok(Point(coords[0], coords[1], coords[2]))
+        let coords: Vec<&str> = s.split(',').map(|n| n.trim()).collect();
+        match coords.as_slice() {
+            [x, y, z] => Ok(Point(
+                parse_token(1, s, x)?,
+                parse_token(1, s, y)?,
+                parse_token(1, s, z)?,
+            )),
+            _ => Err(AocError::parse(
+                1,
+                1,
+                "cubes must have exactly three coordinates",
+            )),
+        }
     }
 }
 
@@ -61,13 +72,8 @@ struct Rock {
 }
 
 impl Rock {
-    /// Creates a new rock from a slice of points.
-    fn new(points: &[Point]) -> Self {
-        let mut cubes = HashSet::new();
-        for &point in points {
-            cubes.insert(point);
-        }
-        assert_eq!(points.len(), cubes.len()); // the slice and the set must have the same length!
+    /// Creates a new rock from a set of points.
+    fn new(cubes: HashSet<Point>) -> Self {
         Self { cubes }
     }
 
@@ -94,7 +100,6 @@ impl Rock {
 
     /// Computes the surface area without considering inner wholes
     fn outer_surface_area(&self) -> usize {
-        let mut res = 0;
         // We will explore the outer space looking for outer rock pixels
         let (min_point, max_point) = self.bounding_cube();
         let distances = [
@@ -105,34 +110,28 @@ impl Rock {
             Point(0, -1, 0),
             Point(0, 0, -1),
         ];
-        // air pixels pending to be explored
-        let mut pending = VecDeque::new();
-        // air pixels that were already explored or are already pending
-        let mut explored_or_pending = HashSet::new();
-        // Initially, only the upper left corner is pending
-        pending.push_back(min_point);
-        explored_or_pending.insert(min_point);
-
-        while !pending.is_empty() {
-            // We get the first pending point and explore its neighbors
-            let point = pending.pop_front().unwrap();
-            for &neighbor in &distances.map(|d| d + point) {
-                // If the neighbor is out of bounds or it is already explored/pending, we continue
-                if Self::out_of_bounds(&neighbor, &min_point, &max_point)
-                    || explored_or_pending.contains(&neighbor)
-                {
-                    continue;
-                } else if self.cubes.contains(&neighbor) {
-                    // Otherwise, if the neighbor is a pixel of the rock, we found an outer surface!
-                    res += 1;
-                } else {
-                    // If not, it is just a outer air pixel that was not already pending
-                    explored_or_pending.insert(neighbor);
-                    pending.push_back(neighbor);
-                }
-            }
-        }
-        res
+        // We flood the outer air starting from the upper left corner (there are no targets)
+        let air = bfs(
+            [min_point],
+            |&point| {
+                distances.map(|d| d + point).into_iter().filter(|neighbor| {
+                    !Self::out_of_bounds(neighbor, &min_point, &max_point)
+                        && !self.cubes.contains(neighbor)
+                })
+            },
+            |_| false,
+        );
+        // Every rock pixel next to an outer air pixel is an outer surface!
+        air.distances()
+            .keys()
+            .map(|&point| {
+                distances
+                    .map(|d| d + point)
+                    .iter()
+                    .filter(|neighbor| self.cubes.contains(neighbor))
+                    .count()
+            })
+            .sum()
     }
 
     /// Checks if a point is out of bounds according to minimum and maximum points
@@ -165,22 +164,48 @@ impl Rock {
         (min - Point(1, 1, 1), max + Point(1, 1, 1))
     }
 }
-/// Reads the input file and returns the list of measurements.
-fn read_input(path: &str) -> Rock {
-    let file = File::open(path).expect("input file not found");
-    let reader = BufReader::new(file);
-    let lines = reader.lines();
-    let cubes: Vec<_> = lines
-        .map(|l| Point::from_str(&l.expect("unable to parse line")).unwrap())
-        .collect();
-    Rock::new(&cubes)
+
+/// Reads the input and returns the rock.
+/// Every cube must appear only once.
+fn read_input(input: &str) -> Result<Rock, AocError> {
+    let mut cubes = HashSet::new();
+    for (i, line) in input.lines().enumerate() {
+        let cube = Point::from_str(line).map_err(|e| e.offset_lines(i))?;
+        if !cubes.insert(cube) {
+            return Err(AocError::parse(
+                i + 1,
+                1,
+                format!("duplicated cube {}", cube),
+            ));
+        }
+    }
+    Ok(Rock::new(cubes))
+}
+
+/// Solution of the eighteenth day.
+#[derive(Debug)]
+pub struct Day18 {
+    /// Scanned lava droplet.
+    rock: Rock,
 }
 
-fn main() {
-    let rock = read_input("data/18_input.txt");
-    println!("The surface area of the rock is {}", rock.surface_area());
-    println!(
-        "The outer surface area of the rock is {}",
-        rock.outer_surface_area()
-    );
+impl Solution for Day18 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            rock: read_input(input)?,
+        })
+    }
+
+    /// Surface area of the lava droplet.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self.rock.surface_area())
+    }
+
+    /// Exterior surface area of the lava droplet.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self.rock.outer_surface_area())
+    }
 }
@@ -0,0 +1,331 @@
+//! Day 19: Not Enough Minerals.
+
+use crate::diag::diag;
+use crate::error::{location_of, parse_token};
+use crate::par;
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Auxiliary structure for storing the number of resources and robots
+type Resource = (usize, usize, usize, usize);
+
+/// Structure that represents a point of the rope
+#[derive(Debug, Clone)]
+struct Blueprint {
+    ore_robot: usize,
+    clay_robot: usize,
+    obsidian_robot: (usize, usize),
+    geode_robot: (usize, usize),
+}
+
+impl Blueprint {
+    /// It returns the maximum required number of ore that I may consume in a minute
+    fn max_ore_per_minute(&self) -> usize {
+        max(
+            self.ore_robot,
+            max(
+                self.clay_robot,
+                max(self.obsidian_robot.0, self.geode_robot.0),
+            ),
+        )
+    }
+
+    /// It returns the maximum required number of clay that I may consume in a minute
+    fn max_clay_per_minute(&self) -> usize {
+        self.obsidian_robot.1
+    }
+
+    /// It returns the maximum required number of obsidian that I may consume in a minute.
+    fn max_obsidian_per_minute(&self) -> usize {
+        self.geode_robot.1
+    }
+
+    /// Returns the maximum number of geodes that we can get given a time limit
+    fn best_scenario(&self, max_t: usize) -> usize {
+        let mut cache = HashMap::new();
+        // Initially, we only have an ore robot
+        self.best_scenario_mem((0, 0, 0, 0), (1, 0, 0, 0), max_t, &mut cache)
+    }
+
+    /// Analyzes the best scenario using memoization.
+    fn best_scenario_mem(
+        &self,
+        resources: Resource,
+        robots: Resource,
+        t: usize,
+        mem: &mut HashMap<(Resource, Resource, usize), usize>,
+    ) -> usize {
+        // Stop condition: we ran out of time. We return the number of geodes
+        if t == 0 {
+            return resources.3;
+        }
+        // Next we check if we already explored an scenario
+        let (mut ore, mut clay, mut obsidian, geode) = resources;
+        let (mut ore_r, mut clay_r, mut obsidian_r, geode_r) = robots;
+        // To increase the number of cache hits, we limit the number of robots to the maximum needed
+        ore_r = min(ore_r, self.max_ore_per_minute());
+        clay_r = min(clay_r, self.max_clay_per_minute());
+        obsidian_r = min(obsidian_r, self.max_obsidian_per_minute());
+        // We also limit the number of resources. If we have plenty, we limit them to the maximum.
+        if ore + ore_r * (t - 1) >= self.max_ore_per_minute() * t {
+            ore = self.max_ore_per_minute() * t - ore_r * (t - 1);
+        }
+        if clay + clay_r * (t - 1) >= self.max_clay_per_minute() * t {
+            clay = self.max_clay_per_minute() * t - clay_r * (t - 1);
+        }
+        if obsidian + obsidian_r * (t - 1) >= self.max_obsidian_per_minute() * t {
+            obsidian = self.max_obsidian_per_minute() * t - obsidian_r * (t - 1);
+        }
+        let mem_key = (
+            (ore, clay, obsidian, geode),
+            (ore_r, clay_r, obsidian_r, geode_r),
+            t,
+        );
+        // Stop condition: we have the result already cached
+        if mem.contains_key(&mem_key) {
+            return *mem.get(&mem_key).unwrap();
+        }
+
+        // By default, we assume that no robots are created in the next step.
+        let next_n_resources = (
+            ore + ore_r,
+            clay + clay_r,
+            obsidian + obsidian_r,
+            geode + geode_r,
+        );
+        let mut best_score = self.best_scenario_mem(next_n_resources, robots, t - 1, mem);
+        // We check what happens if we create an ore robot
+        if ore >= self.ore_robot {
+            let mut aux_resources = next_n_resources;
+            aux_resources.0 -= self.ore_robot;
+            let aux_robots = (ore_r + 1, clay_r, obsidian_r, geode_r);
+            best_score = max(
+                best_score,
+                self.best_scenario_mem(aux_resources, aux_robots, t - 1, mem),
+            );
+        }
+        // If we have enough ore, we check what happens if we create a clay robot
+        if ore >= self.clay_robot {
+            let mut aux_resources = next_n_resources;
+            aux_resources.0 -= self.clay_robot;
+            let aux_robots = (ore_r, clay_r + 1, obsidian_r, geode_r);
+            best_score = max(
+                best_score,
+                self.best_scenario_mem(aux_resources, aux_robots, t - 1, mem),
+            );
+        }
+        // If we have enough ore and clay, we check what happens if we create an obsidian robot
+        if ore >= self.obsidian_robot.0 && clay >= self.obsidian_robot.1 {
+            let mut aux_resources = next_n_resources;
+            aux_resources.0 -= self.obsidian_robot.0;
+            aux_resources.1 -= self.obsidian_robot.1;
+            let aux_robots = (ore_r, clay_r, obsidian_r + 1, geode_r);
+            best_score = max(
+                best_score,
+                self.best_scenario_mem(aux_resources, aux_robots, t - 1, mem),
+            );
+        }
+        // If we have enough ore and obsidian, we check what happens if we create an geode robot
+        if ore >= self.geode_robot.0 && obsidian >= self.geode_robot.1 {
+            let mut aux_resources = next_n_resources;
+            aux_resources.0 -= self.geode_robot.0;
+            aux_resources.2 -= self.geode_robot.1;
+            let aux_robots = (ore_r, clay_r, obsidian_r, geode_r + 1);
+            best_score = max(
+                best_score,
+                self.best_scenario_mem(aux_resources, aux_robots, t - 1, mem),
+            );
+        }
+        // Once we are done, we store the result in the cache and return the best score
+        mem.insert(mem_key, best_score);
+        best_score
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = AocError;
+
+    /// Blueprints may span several lines. Errors are reported relative to the start of `s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(
+            r"^Blueprint\s+\d+:\s+Each\s+ore\s+robot\s+costs\s+(\d+)\s+ore\.\s+Each\s+clay\s+robot\s+costs\s+(\d+)\s+ore\.\s+Each\s+obsidian\s+robot\s+costs\s+(\d+)\s+ore\s+and\s+(\d+)\s+clay\.\s+Each\s+geode\s+robot\s+costs\s+(\d+)\s+ore\s+and\s+(\d+)\s+obsidian\.\s*$",
+        )
+        .unwrap();
+        let cap = re.captures(s).ok_or_else(|| {
+            AocError::parse(1, 1, "blueprint does not follow the expected format")
+        })?;
+        let mut numbers = Vec::new();
+        // All the groups always participate in the match
+        for number in cap.iter().skip(1).flatten() {
+            let number = number.as_str();
+            numbers.push(parse_token(1, number, number).map_err(|e| e.within(s, number))?);
+        }
+        Ok(Blueprint {
+            ore_robot: numbers[0],
+            clay_robot: numbers[1],
+            obsidian_robot: (numbers[2], numbers[3]),
+            geode_robot: (numbers[4], numbers[5]),
+        })
+    }
+}
+
+/// Reads the input and returns the list of blueprints.
+/// Every blueprint starts with the word "Blueprint", and it may span several lines.
+fn read_input(input: &str) -> Result<Vec<Blueprint>, AocError> {
+    let starts: Vec<usize> = input.match_indices("Blueprint").map(|(i, _)| i).collect();
+    // There must be nothing but blanks before the first blueprint
+    let head = &input[..starts.first().copied().unwrap_or(input.len())];
+    if let Some(garbage) = head.split_whitespace().next() {
+        let (line, column) = location_of(input, garbage);
+        return Err(AocError::parse(line, column, "expected a blueprint"));
+    }
+    let mut res = Vec::new();
+    for (k, &start) in starts.iter().enumerate() {
+        let end = starts.get(k + 1).copied().unwrap_or(input.len());
+        let text = &input[start..end];
+        res.push(Blueprint::from_str(text).map_err(|e| e.within(input, text))?);
+    }
+    Ok(res)
+}
+
+/// It sums the the quality index of every blueprint
+fn exercise_1(blueprints: &[Blueprint], max_t: usize) -> usize {
+    // Blueprints are independent with each other, so I decided to explore them in parallel
+    let geodes = par::map(blueprints, |b| b.best_scenario(max_t));
+    // Results come in the same order as the blueprints, so we can sum their quality level.
+    let mut res = 0;
+    for (i, geode) in geodes.into_iter().enumerate() {
+        diag!("Blueprint {} achieved {} geodes", i + 1, geode);
+        res += (i + 1) * geode;
+    }
+    res
+}
+
+/// It multiplies the obtained geodes by all the blueprints
+fn exercise_2(blueprints: &[Blueprint], max_t: usize) -> usize {
+    // Blueprints are independent with each other, so I decided to explore them in parallel
+    let geodes = par::map(blueprints, |b| b.best_scenario(max_t));
+    // We multiply the geodes achieved.
+    let mut res = 1;
+    for (i, geode) in geodes.into_iter().enumerate() {
+        diag!("Blueprint {} achieved {} geodes", i + 1, geode);
+        res *= geode;
+    }
+    res
+}
+
+/// Solution of the nineteenth day.
+#[derive(Debug, Clone)]
+pub struct Day19 {
+    /// List of blueprints.
+    blueprints: Vec<Blueprint>,
+}
+
+impl Day19 {
+    /// It returns the maximum number of geodes that every blueprint opens in `minutes`.
+    pub fn geodes(&self, minutes: usize) -> Vec<usize> {
+        par::map(&self.blueprints, |b| b.best_scenario(minutes))
+    }
+}
+
+impl Solution for Day19 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            blueprints: read_input(input)?,
+        })
+    }
+
+    /// Sum of the quality levels of all the blueprints after 24 minutes.
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(exercise_1(&self.blueprints, 24))
+    }
+
+    /// Product of the geodes opened by the first three blueprints after 32 minutes.
+    fn part2(&self) -> Result<usize, AocError> {
+        // For the second part, we just keep the three first blueprints
+        let n = self.blueprints.len().min(3);
+        Ok(exercise_2(&self.blueprints[..n], 32))
+    }
+}
+
+/// Slow but obviously correct solver, so we can check the fast one (see `tests/differential.rs`).
+/// It tries every decision in every minute and it only remembers the states that it already saw,
+/// without capping the robots or the resources. It only works with a few minutes.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    /// List of blueprints.
+    blueprints: Vec<Blueprint>,
+}
+
+impl Reference {
+    /// It parses the puzzle input.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            blueprints: read_input(input)?,
+        })
+    }
+
+    /// It returns the maximum number of geodes that every blueprint opens in `minutes`.
+    pub fn geodes(&self, minutes: usize) -> Vec<usize> {
+        let explore =
+            |b: &Blueprint| Self::explore(b, [0; 4], [1, 0, 0, 0], minutes, &mut HashMap::new());
+        self.blueprints.iter().map(explore).collect()
+    }
+
+    /// It returns the maximum number of geodes that we open in the remaining `t` minutes.
+    /// Resources and robots are sorted: ore, clay, obsidian, and geode.
+    fn explore(
+        blueprint: &Blueprint,
+        resources: [usize; 4],
+        robots: [usize; 4],
+        t: usize,
+        seen: &mut HashMap<([usize; 4], [usize; 4], usize), usize>,
+    ) -> usize {
+        if t == 0 {
+            return resources[3];
+        }
+        if let Some(&geodes) = seen.get(&(resources, robots, t)) {
+            return geodes;
+        }
+        // Costs of every robot, in the same order as the resources
+        let costs = [
+            [blueprint.ore_robot, 0, 0, 0],
+            [blueprint.clay_robot, 0, 0, 0],
+            [blueprint.obsidian_robot.0, blueprint.obsidian_robot.1, 0, 0],
+            [blueprint.geode_robot.0, 0, blueprint.geode_robot.1, 0],
+        ];
+        // We may build nothing, or any robot that we can afford
+        let mut options = vec![None];
+        options.extend(
+            (0..4)
+                .filter(|&r| (0..4).all(|i| resources[i] >= costs[r][i]))
+                .map(Some),
+        );
+        let mut best = 0;
+        for option in options {
+            let (mut next_resources, mut next_robots) = (resources, robots);
+            for i in 0..4 {
+                // New robots start working in the next minute
+                next_resources[i] += robots[i];
+                if let Some(r) = option {
+                    next_resources[i] -= costs[r][i];
+                }
+            }
+            if let Some(r) = option {
+                next_robots[r] += 1;
+            }
+            best = max(
+                best,
+                Self::explore(blueprint, next_resources, next_robots, t - 1, seen),
+            );
+        }
+        seen.insert((resources, robots, t), best);
+        best
+    }
+}
@@ -1,15 +1,27 @@
+//! Day 20: Grove Positioning System.
+
+use crate::error::parse_token;
+use crate::{AocError, Solution};
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
 
-/// Reads the input file and returns the list of blueprints.
-fn read_input(path: &str) -> Vec<i64> {
-    let file = File::open(path).expect("input file not found");
-    let reader = BufReader::new(file);
-    let lines = reader.lines();
-    lines
-        .map(|l| l.expect("unable to parse line").parse::<i64>().unwrap())
-        .collect()
+/// Reads the input and returns the encrypted file.
+/// The file must contain at least two numbers, and one of them must be 0.
+fn read_input(input: &str) -> Result<Vec<i64>, AocError> {
+    let mut res = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        res.push(parse_token(i + 1, line, line)?);
+    }
+    if res.len() < 2 {
+        return Err(AocError::unexpected_end(
+            res.len() + 1,
+            "at least two numbers",
+        ));
+    }
+    if !res.contains(&0) {
+        return Err(AocError::no_solution("the file does not contain any 0"));
+    }
+    Ok(res)
 }
 
 /// It decodes the input message
@@ -62,9 +74,33 @@ fn decode(code: &[i64], hash: i64, n_mixes: usize) -> i64 {
     n1 + n2 + n3
 }
 
-fn main() {
-    let code = read_input("data/20_input.txt");
-    // println!("{:?}", &code);
-    println!("{}", decode(&code, 1, 1));
-    println!("{}", decode(&code, 811589153, 10));
+/// Decryption key used in the second exercise.
+const DECRYPTION_KEY: i64 = 811589153;
+
+/// Solution of the twentieth day.
+#[derive(Debug, Clone)]
+pub struct Day20 {
+    /// Encrypted file.
+    code: Vec<i64>,
+}
+
+impl Solution for Day20 {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            code: read_input(input)?,
+        })
+    }
+
+    /// Sum of the grove coordinates after mixing once.
+    fn part1(&self) -> Result<i64, AocError> {
+        Ok(decode(&self.code, 1, 1))
+    }
+
+    /// Sum of the grove coordinates after applying the key and mixing ten times.
+    fn part2(&self) -> Result<i64, AocError> {
+        Ok(decode(&self.code, DECRYPTION_KEY, 10))
+    }
 }
@@ -1,6 +1,8 @@
+//! Day 21: Monkey Math.
+
+use crate::error::{column_of, parse_token};
+use crate::{AocError, Solution};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
@@ -32,24 +34,28 @@ impl Monkey {
 }
 
 impl FromStr for Monkey {
-    type Err = &'static str;
+    type Err = AocError;
 
+    /// Errors are reported in line 1, and columns are relative to the string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let chunks: Vec<_> = s.trim().split(' ').collect();
-        match chunks.len() {
-            1 => match chunks[0].parse::<i64>() {
-                Ok(n) => Ok(Self::Num(n)),
-                Err(_) => Err("unable to parse number as i64"),
-            },
-            3 => match chunks[1] {
-                "+" | "-" | "*" | "/" => Ok(Self::Op((
-                    chunks[0].to_string(),
-                    chunks[1].to_string(),
-                    chunks[2].to_string(),
-                ))),
-                _ => Err("unknown operation"),
+        let chunks: Vec<_> = s.split_whitespace().collect();
+        match chunks.as_slice() {
+            [n] => Ok(Self::Num(parse_token(1, s, n)?)),
+            [lhs, op, rhs] => match *op {
+                "+" | "-" | "*" | "/" => {
+                    Ok(Self::Op((lhs.to_string(), op.to_string(), rhs.to_string())))
+                }
+                _ => Err(AocError::parse(
+                    1,
+                    column_of(s, op),
+                    format!("unknown operation {:?}", op),
+                )),
             },
-            _ => Err("incorrect number of parameters"),
+            _ => Err(AocError::parse(
+                1,
+                column_of(s, s.trim_start()),
+                "expected a number or an operation",
+            )),
         }
     }
 }
@@ -156,22 +162,41 @@ impl Node {
     }
 }
 
-/// Reads the input file and returns the list of blueprints.
-fn read_input(path: &str) -> HashMap<String, Monkey> {
-    let file = File::open(path).expect("input file not found");
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            let l_ok = l.expect("unable to read line");
-            let aux: Vec<_> = l_ok.split(':').collect();
-            (
-                aux[0].to_string(),
-                Monkey::from_str(aux[1]).expect("unable to parse monkey"),
-            )
-        })
-        .collect()
+/// Reads the input and returns the monkeys indexed by their names.
+/// All the monkeys referenced in operations must exist.
+fn read_input(input: &str) -> Result<HashMap<String, Monkey>, AocError> {
+    let mut monkeys = HashMap::new();
+    let mut references = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let (name, job) = line
+            .split_once(':')
+            .ok_or_else(|| AocError::parse(i + 1, 1, "expected \"name: job\""))?;
+        let monkey = Monkey::from_str(job).map_err(|e| e.within(line, job).offset_lines(i))?;
+        if let Monkey::Op((lhs, _, rhs)) = &monkey {
+            let mut tokens = job.split_whitespace();
+            for (name, token) in [lhs, rhs].into_iter().zip([tokens.next(), tokens.nth(1)]) {
+                references.push((i, name.clone(), token.map_or(1, |t| column_of(line, t))));
+            }
+        }
+        let name = name.trim();
+        if monkeys.insert(name.to_string(), monkey).is_some() {
+            return Err(AocError::parse(
+                i + 1,
+                column_of(line, name),
+                format!("duplicated monkey {:?}", name),
+            ));
+        }
+    }
+    for (i, name, column) in references {
+        if !monkeys.contains_key(&name) {
+            return Err(AocError::parse(
+                i + 1,
+                column,
+                format!("unknown monkey {:?}", name),
+            ));
+        }
+    }
+    Ok(monkeys)
 }
 
 fn exercise_1(monkeys: &HashMap<String, Monkey>, target: &str) -> Option<i64> {
@@ -205,8 +230,51 @@ fn exercise_2(monkeys: &HashMap<String, Monkey>, target: &str, human: &str) -> R
     }
 }
 
-fn main() {
-    let monkeys = read_input("data/21_input.txt");
-    println!("{:?}", exercise_1(&monkeys, "root"));
-    println!("{:?}", exercise_2(&monkeys, "root", "humn"));
+/// Name of the monkey that yells the final number.
+const ROOT: &str = "root";
+/// Name I use when I pretend to be a monkey.
+const HUMAN: &str = "humn";
+
+/// It checks that a given monkey is part of the riddle.
+fn check_monkey(monkeys: &HashMap<String, Monkey>, name: &str) -> Result<(), AocError> {
+    match monkeys.contains_key(name) {
+        true => Ok(()),
+        false => Err(AocError::no_solution(format!(
+            "monkey {:?} not found",
+            name
+        ))),
+    }
+}
+
+/// Solution of the twenty-first day.
+#[derive(Debug, Clone)]
+pub struct Day21 {
+    /// Monkeys indexed by their names.
+    monkeys: HashMap<String, Monkey>,
+}
+
+impl Solution for Day21 {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            monkeys: read_input(input)?,
+        })
+    }
+
+    /// Number yelled by the root monkey.
+    fn part1(&self) -> Result<i64, AocError> {
+        check_monkey(&self.monkeys, ROOT)?;
+        exercise_1(&self.monkeys, ROOT)
+            .ok_or_else(|| AocError::no_solution("unable to compute the root number"))
+    }
+
+    /// Number I need to yell to pass root's equality test.
+    fn part2(&self) -> Result<i64, AocError> {
+        check_monkey(&self.monkeys, ROOT)?;
+        check_monkey(&self.monkeys, HUMAN)?;
+        exercise_2(&self.monkeys, ROOT, HUMAN)
+            .map_err(|_| AocError::no_solution("unable to solve the equation"))
+    }
 }
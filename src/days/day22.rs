@@ -0,0 +1,563 @@
+//! Day 22: Monkey Map.
+
+use crate::diag::diag;
+use crate::export::{Image, Paint, Palette, Render, Rgb, BACKGROUND};
+use crate::geom::{Direction, Point, Turn};
+use crate::grid::Grid;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Tile {
+    Air,
+    Open,
+    Wall,
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            ' ' => Some(Self::Air),
+            '.' => Some(Self::Open),
+            '#' => Some(Self::Wall),
+            _ => None,
+        }
+    }
+
+    fn is_air(&self) -> bool {
+        matches!(self, Self::Air)
+    }
+
+    fn is_open(&self) -> bool {
+        matches!(self, Self::Open)
+    }
+
+    fn is_wall(&self) -> bool {
+        matches!(self, Self::Wall)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Move {
+    Steps(u32),
+    Turn(Turn),
+}
+
+impl Move {
+    fn get_steps(&self) -> Option<u32> {
+        match self {
+            Self::Steps(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+/// Transitions between the faces of the cube.
+/// Keys are (previous face, previous direction), and values are (new face, new direction).
+type CubeMap = HashMap<(Point, Direction), (Point, Direction)>;
+
+#[derive(Debug, Clone)]
+struct State {
+    location: Point,
+    direction: Direction,
+}
+
+impl State {
+    /// It returns the initial state: the leftmost open tile of the top row, facing right.
+    fn new(tiles: &Grid<Tile>) -> Option<Self> {
+        Some(Self {
+            location: tiles.positions(|t| t.is_open()).next()?,
+            direction: Direction::Right,
+        })
+    }
+
+    fn step_plane(&mut self, n: u32, tiles: &Grid<Tile>) {
+        for _ in 0..n {
+            assert!(tiles[self.location].is_open());
+            // First we make sure that we are not out of the scenario
+            let mut next_location = tiles.wrap(self.location + self.direction.vector());
+            // Next we wrap in case of finding void tiles
+            while tiles[next_location].is_air() {
+                next_location = tiles.wrap(next_location + self.direction.vector());
+            }
+            // If the tile is a wall, we don't move anymore
+            if tiles[next_location].is_wall() {
+                break;
+            }
+            self.location = next_location;
+        }
+    }
+
+    fn step_cube(&mut self, n: u32, tiles: &Grid<Tile>, cube_dim: i32, cube_map: &CubeMap) {
+        for _ in 0..n {
+            assert!(tiles[self.location].is_open());
+            let mut next_location = self.location + self.direction.vector();
+            let mut next_direction = self.direction;
+            // If we are in the middle of nowhere, we need to find the corresponding cube face
+            if !matches!(tiles.get(next_location), Some(t) if !t.is_air()) {
+                let current_face =
+                    Point::new(self.location.x / cube_dim, self.location.y / cube_dim);
+                let (next_face, next_dir) = cube_map[&(current_face, self.direction)];
+                // Position along the edge that we cross. It is the same for both faces
+                let (x, y) = (self.location.x % cube_dim, self.location.y % cube_dim);
+                let edge = match self.direction {
+                    Direction::Right => y,
+                    Direction::Down => cube_dim - 1 - x,
+                    Direction::Left => cube_dim - 1 - y,
+                    Direction::Up => x,
+                };
+                // We enter the new face through the edge opposite to the new direction
+                let edge = match next_dir {
+                    Direction::Right => Point::new(0, edge),
+                    Direction::Down => Point::new(cube_dim - 1 - edge, 0),
+                    Direction::Left => Point::new(cube_dim - 1, cube_dim - 1 - edge),
+                    Direction::Up => Point::new(edge, cube_dim - 1),
+                };
+                next_location = next_face * cube_dim + edge;
+                next_direction = next_dir;
+            }
+            if tiles[next_location].is_wall() {
+                break;
+            }
+            self.location = next_location;
+            self.direction = next_direction;
+        }
+    }
+
+    fn turn(&mut self, turn: Turn) {
+        self.direction = self.direction.turn(turn);
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Scenario {
+    tiles: Grid<Tile>,
+    moves: Vec<Move>,
+    state: State,
+}
+
+impl Scenario {
+    fn new(tiles: Grid<Tile>, moves: Vec<Move>) -> Result<Self, AocError> {
+        let state = State::new(&tiles)
+            .ok_or_else(|| AocError::no_solution("unable to find an open tile"))?;
+        Ok(Self {
+            tiles,
+            moves,
+            state,
+        })
+    }
+
+    fn exercise_1(&mut self) {
+        diag!(
+            "INITIAL STATE: {:?} ({:?})",
+            self.state.location,
+            self.state.direction
+        );
+        for &m in self.moves.iter() {
+            match m {
+                Move::Steps(n) => self.state.step_plane(n, &self.tiles),
+                Move::Turn(turn) => self.state.turn(turn),
+            }
+        }
+        diag!(
+            "FINAL STATE: {:?} ({:?})",
+            self.state.location,
+            self.state.direction
+        );
+    }
+
+    fn exercise_2(&mut self, cube_dim: i32, cube_map: &CubeMap) {
+        diag!(
+            "INITIAL STATE: {:?} ({:?})",
+            self.state.location,
+            self.state.direction
+        );
+        for &m in self.moves.iter() {
+            match m {
+                Move::Steps(n) => self.state.step_cube(n, &self.tiles, cube_dim, cube_map),
+                Move::Turn(turn) => self.state.turn(turn),
+            }
+        }
+        diag!(
+            "FINAL STATE: {:?} ({:?})",
+            self.state.location,
+            self.state.direction
+        );
+    }
+}
+
+/// Reads the input and returns the scenario.
+/// The map and the path are separated by a blank line.
+fn read_input(input: &str) -> Result<Scenario, AocError> {
+    let mut rows: Vec<_> = input.lines().collect();
+    // We ignore trailing blank lines
+    while rows.last().is_some_and(|l| l.trim().is_empty()) {
+        rows.pop();
+    }
+    let n_lines = rows.len();
+    let instructions = rows
+        .pop()
+        .ok_or_else(|| AocError::unexpected_end(1, "the map"))?;
+    match rows.pop() {
+        Some(blank) if blank.trim().is_empty() => {}
+        Some(_) => return Err(AocError::parse(n_lines - 1, 1, "expected a blank line")),
+        None => return Err(AocError::unexpected_end(n_lines, "the path")),
+    }
+
+    // Lines may have different lengths, so we fill the gaps with air
+    let n_cols = rows
+        .iter()
+        .map(|r| r.chars().count())
+        .max()
+        .unwrap_or_default();
+    let mut tiles = Grid::new(rows.len(), n_cols, Tile::Air);
+    for (i, l) in rows.into_iter().enumerate() {
+        for (j, c) in l.chars().enumerate() {
+            let tile = Tile::new(c).ok_or(AocError::unexpected_char(i + 1, j + 1, c))?;
+            tiles[Point::new(j as i32, i as i32)] = tile;
+        }
+    }
+
+    let mut moves: Vec<Move> = Vec::new();
+    for (j, c) in instructions.trim_end().chars().enumerate() {
+        if let Some(mut n) = c.to_digit(10) {
+            if let Some(prev) = moves.last().and_then(|m| m.get_steps()) {
+                moves.pop();
+                n = prev
+                    .checked_mul(10)
+                    .and_then(|x| x.checked_add(n))
+                    .ok_or_else(|| AocError::parse(n_lines, j + 1, "too many steps"))?;
+            }
+            moves.push(Move::Steps(n));
+        } else if c == 'R' {
+            moves.push(Move::Turn(Turn::Right));
+        } else if c == 'L' {
+            moves.push(Move::Turn(Turn::Left));
+        } else {
+            return Err(AocError::unexpected_char(n_lines, j + 1, c));
+        }
+    }
+    Scenario::new(tiles, moves)
+}
+
+fn password(scenario: &Scenario) -> i32 {
+    let (col, row) = (scenario.state.location.x + 1, scenario.state.location.y + 1);
+    let facing = match scenario.state.direction {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+    1000 * row + 4 * col + facing
+}
+
+/// It returns the length of the edges of the cube.
+/// If the number of tiles does not fit in a cube, it returns `None`.
+fn cube_dim(tiles: &Grid<Tile>) -> Option<i32> {
+    let n_tiles = tiles.positions(|t| !t.is_air()).count();
+    let dim = (1..).find(|d| 6 * d * d >= n_tiles)?;
+    match 6 * dim * dim == n_tiles {
+        true => Some(dim as i32),
+        false => None,
+    }
+}
+
+/// 3D vector used for folding the cube.
+type Vec3 = (i32, i32, i32);
+
+/// It returns the opposite of a 3D vector.
+fn neg(v: Vec3) -> Vec3 {
+    (-v.0, -v.1, -v.2)
+}
+
+/// Orientation of a face of the cube once it is folded.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    /// Vector pointing outside the cube.
+    normal: Vec3,
+    /// Vector pointing to the right of the face in the map.
+    right: Vec3,
+    /// Vector pointing to the bottom of the face in the map.
+    down: Vec3,
+}
+
+impl Face {
+    /// It returns the 3D vector of a direction of the map.
+    fn axis(&self, direction: Direction) -> Vec3 {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => neg(self.right),
+            Direction::Up => neg(self.down),
+        }
+    }
+
+    /// It returns the orientation of the face that we reach if we leave this face to `direction`.
+    /// The new face is folded around the edge: it looks towards `direction`,
+    /// and moving towards `direction` in the new face takes us to the back of the cube.
+    fn fold(&self, direction: Direction) -> Self {
+        let mut res = Self {
+            normal: self.axis(direction),
+            ..*self
+        };
+        match direction {
+            Direction::Right => res.right = neg(self.normal),
+            Direction::Down => res.down = neg(self.normal),
+            Direction::Left => res.right = self.normal,
+            Direction::Up => res.down = self.normal,
+        }
+        res
+    }
+}
+
+/// It folds the map as a cube and returns the transitions between its faces.
+/// If the map is not the net of a cube, it returns `None`.
+fn cube_map(tiles: &Grid<Tile>, cube_dim: i32) -> Option<CubeMap> {
+    // First, we look for the faces in the map. Faces must be completely filled.
+    let mut net = Vec::new();
+    for j in (0..tiles.n_rows() as i32).step_by(cube_dim as usize) {
+        for i in (0..tiles.n_cols() as i32).step_by(cube_dim as usize) {
+            let n_tiles = (0..cube_dim)
+                .flat_map(|y| (0..cube_dim).map(move |x| Point::new(i + x, j + y)))
+                .filter(|&p| matches!(tiles.get(p), Some(t) if !t.is_air()))
+                .count();
+            match n_tiles as i32 {
+                0 => {}
+                n if n == cube_dim * cube_dim => net.push(Point::new(i, j) / cube_dim),
+                _ => return None,
+            }
+        }
+    }
+    // Next, we fold the faces one by one starting from the first one
+    let mut faces = HashMap::new();
+    let first = *net.first()?;
+    faces.insert(
+        first,
+        Face {
+            normal: (0, 0, 1),
+            right: (1, 0, 0),
+            down: (0, 1, 0),
+        },
+    );
+    let mut pending = vec![first];
+    while let Some(current) = pending.pop() {
+        for direction in Direction::ALL {
+            let next = current + direction.vector();
+            if net.contains(&next) && !faces.contains_key(&next) {
+                let face = faces[&current].fold(direction);
+                faces.insert(next, face);
+                pending.push(next);
+            }
+        }
+    }
+    if net.len() != 6 || faces.len() != 6 {
+        return None;
+    }
+    // Finally, for every edge we look for the face on the other side and the new direction
+    let mut cube_map = HashMap::new();
+    for (&current, face) in faces.iter() {
+        for direction in Direction::ALL {
+            let normal = face.axis(direction);
+            let (&next, next_face) = faces.iter().find(|(_, f)| f.normal == normal)?;
+            let next_direction = Direction::ALL
+                .into_iter()
+                .find(|&d| next_face.axis(d) == neg(face.normal))?;
+            cube_map.insert((current, direction), (next, next_direction));
+        }
+    }
+    Some(cube_map)
+}
+
+/// It returns the length of the edges of the cube and the transitions between its faces.
+fn cube(tiles: &Grid<Tile>) -> Result<(i32, CubeMap), AocError> {
+    let cube_dim =
+        cube_dim(tiles).ok_or_else(|| AocError::no_solution("the map does not fit in a cube"))?;
+    let cube_map = cube_map(tiles, cube_dim)
+        .ok_or_else(|| AocError::no_solution("the map is not the net of a cube"))?;
+    Ok((cube_dim, cube_map))
+}
+
+/// What we see in every tile of the animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Tile(Tile),
+    /// Open tile that we already walked through (with our last direction there).
+    Trail(Direction),
+    /// Our current location (with our current direction).
+    You(Direction),
+}
+
+impl Cell {
+    /// Symbol of the cell in the terminal (as in the puzzle statement).
+    fn symbol(self) -> char {
+        match self {
+            Cell::Tile(Tile::Air) => ' ',
+            Cell::Tile(Tile::Open) => '.',
+            Cell::Tile(Tile::Wall) => '#',
+            Cell::Trail(direction) => direction.arrow(),
+            Cell::You(_) => '@',
+        }
+    }
+}
+
+impl Paint for Cell {
+    const KINDS: &'static [&'static str] = &["air", "open", "wall", "trail", "you"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Cell::Tile(Tile::Air) => "air",
+            Cell::Tile(Tile::Open) => "open",
+            Cell::Tile(Tile::Wall) => "wall",
+            Cell::Trail(_) => "trail",
+            Cell::You(_) => "you",
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Cell::Tile(Tile::Air) => BACKGROUND,
+            Cell::Tile(Tile::Open) => [0x33, 0x33, 0x55],
+            Cell::Tile(Tile::Wall) => [0x99, 0x99, 0x99],
+            Cell::Trail(_) => [0xff, 0xff, 0x66],
+            Cell::You(_) => [0xff, 0x00, 0x00],
+        }
+    }
+}
+
+/// Animation of our walk through the map, one move of the path at a time.
+struct Animation {
+    scenario: Scenario,
+    /// Size and transitions of the cube (only in the second part).
+    cube: Option<(i32, CubeMap)>,
+    /// Tiles that we already walked through.
+    trail: HashMap<Point, Direction>,
+    /// Number of moves already done.
+    n: usize,
+}
+
+impl Animation {
+    /// It returns the cell of a point.
+    fn cell(&self, point: Point) -> Cell {
+        let state = &self.scenario.state;
+        match self.trail.get(&point) {
+            _ if point == state.location => Cell::You(state.direction),
+            Some(&direction) => Cell::Trail(direction),
+            None => Cell::Tile(self.scenario.tiles[point]),
+        }
+    }
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        let scenario = &mut self.scenario;
+        let Some(&m) = scenario.moves.get(self.n) else {
+            return false;
+        };
+        let state = &mut scenario.state;
+        self.trail.insert(state.location, state.direction);
+        match m {
+            Move::Steps(n) => {
+                // We walk one tile at a time, so we leave a trail
+                for _ in 0..n {
+                    match &self.cube {
+                        Some((dim, map)) => state.step_cube(1, &scenario.tiles, *dim, map),
+                        None => state.step_plane(1, &scenario.tiles),
+                    }
+                    self.trail.insert(state.location, state.direction);
+                }
+            }
+            Move::Turn(turn) => state.turn(turn),
+        }
+        self.n += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        let tiles = &self.scenario.tiles;
+        let mut res = String::new();
+        for y in 0..tiles.n_rows() as i32 {
+            for x in 0..tiles.n_cols() as i32 {
+                res.push(self.cell(Point::new(x, y)).symbol());
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "move {}/{}, password {}",
+            self.n,
+            self.scenario.moves.len(),
+            password(&self.scenario)
+        )
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        let tiles = &self.scenario.tiles;
+        Image::from_fn(
+            Point::default(),
+            tiles.n_cols(),
+            tiles.n_rows(),
+            palette,
+            |p| self.cell(p),
+        )
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Cell::KINDS
+    }
+}
+
+/// Solution of the twenty-second day.
+#[derive(Debug, Clone)]
+pub struct Day22 {
+    /// Board, path, and initial state.
+    scenario: Scenario,
+}
+
+impl Day22 {
+    /// Animation of our walk of a given part of the puzzle.
+    /// It fails if the map of the second part cannot be folded as a cube.
+    pub fn animation(&self, part: usize) -> Result<Box<dyn Scene>, AocError> {
+        let cube = match part {
+            1 => None,
+            _ => Some(cube(&self.scenario.tiles)?),
+        };
+        Ok(Box::new(Animation {
+            scenario: self.scenario.clone(),
+            cube,
+            trail: HashMap::new(),
+            n: 0,
+        }))
+    }
+}
+
+impl Solution for Day22 {
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Final password when the map wraps around as a plane.
+    fn part1(&self) -> Result<i32, AocError> {
+        let mut scenario = self.scenario.clone();
+        scenario.exercise_1();
+        Ok(password(&scenario))
+    }
+
+    /// Final password when the map is folded as a cube.
+    fn part2(&self) -> Result<i32, AocError> {
+        let mut scenario = self.scenario.clone();
+        let (cube_dim, cube_map) = cube(&scenario.tiles)?;
+        scenario.exercise_2(cube_dim, &cube_map);
+        Ok(password(&scenario))
+    }
+}
@@ -0,0 +1,257 @@
+//! Day 23: Unstable Diffusion.
+
+use crate::diag::diag;
+use crate::export::{Image, Paint, Palette, Render, Rgb, BACKGROUND};
+use crate::geom::{Direction8, Point};
+use crate::grid::Grid;
+use crate::sparse::SparseGrid;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Tile {
+    Free,
+    Elf,
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Free),
+            '#' => Some(Self::Elf),
+            _ => None,
+        }
+    }
+
+    fn is_free(&self) -> bool {
+        matches!(self, Self::Free)
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Free => ".",
+                Self::Elf => "#",
+            }
+        )
+    }
+}
+
+impl Paint for Tile {
+    const KINDS: &'static [&'static str] = &["ground", "elf"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Free => "ground",
+            Self::Elf => "elf",
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Self::Free => BACKGROUND,
+            Self::Elf => [0x00, 0xcc, 0x00],
+        }
+    }
+}
+
+/// Order in which the elves consider the directions in the first round.
+const DIRS: [Direction8; 4] = [Direction8::N, Direction8::S, Direction8::W, Direction8::E];
+
+/// It returns the three positions that an elf checks before moving towards `direction`
+/// (e.g., `N`, `NE`, and `NW` before moving north).
+fn looking_at(origin: Point, direction: Direction8) -> [Point; 3] {
+    direction.fan().map(|d| origin + d.vector())
+}
+
+#[derive(Debug, Clone)]
+struct Scenario {
+    /// Location of the elves. Free tiles are not stored, as the scenario grows as elves spread.
+    elves: SparseGrid<Tile>,
+}
+
+impl Scenario {
+    fn empty_ground(&self) -> usize {
+        // We look for the smallest rectangle that contains every elf
+        match self.elves.bounds() {
+            Some((min, max)) => {
+                // Every tile of the rectangle that is not an elf is empty ground
+                ((max.x - min.x + 1) * (max.y - min.y + 1)) as usize - self.elves.len()
+            }
+            None => 0,
+        }
+    }
+
+    fn run_until(&mut self, n_iterations: usize) {
+        diag!("== Initial State ==");
+        diag!("{}", self);
+        for n in 0..n_iterations {
+            self.round(n);
+        }
+        diag!("== End of Round {} ==", n_iterations);
+        diag!("{}", self);
+    }
+
+    fn run(&mut self) -> usize {
+        diag!("== Initial State ==");
+        diag!("{}", self);
+        let mut n = 0;
+        while self.round(n) != 0 {
+            n += 1;
+        }
+        diag!("== End of Round {} ==", n + 1);
+        diag!("{}", self);
+        n + 1
+    }
+
+    fn round(&mut self, n: usize) -> usize {
+        // First, we compute the candidates
+        let mut candidates: HashMap<Point, Vec<Point>> = HashMap::new();
+        for (origin, _) in self.elves.iter() {
+            if self.isolated(origin) {
+                // If the elf is isolated, we stop
+                continue;
+            }
+            for x in 0..DIRS.len() {
+                let direction = DIRS[(n + x) % DIRS.len()];
+                if self.all_free(&looking_at(origin, direction)) {
+                    let destination = origin + direction.vector();
+                    candidates.entry(destination).or_default().push(origin);
+                    break;
+                }
+            }
+        }
+        // If there are more than one candidates for a destination, we ignore them
+        candidates.retain(|_, from| from.len() == 1);
+        // Finally, we move the elves. The scenario grows on its own if needed
+        for (&destination, from) in candidates.iter() {
+            assert_eq!(Some(Tile::Elf), self.elves.remove(from[0]));
+            assert_eq!(None, self.elves.insert(destination, Tile::Elf));
+        }
+        candidates.len()
+    }
+
+    fn isolated(&self, origin: Point) -> bool {
+        self.all_free(&Direction8::ALL.map(|d| origin + d.vector()))
+    }
+
+    /// It returns true if there are no elves in any of the points.
+    fn all_free(&self, points: &[Point]) -> bool {
+        points.iter().all(|&point| !self.elves.contains(point))
+    }
+}
+
+impl Display for Scenario {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let elves = self.elves.render(|t| *t.unwrap_or(&Tile::Free));
+        write!(f, "{}", elves)
+    }
+}
+
+/// Animation of the elves, one round at a time.
+struct Animation {
+    scenario: Scenario,
+    /// Number of rounds already done.
+    n: usize,
+    /// Maximum number of rounds (if any).
+    limit: Option<usize>,
+    /// If true, no elf moved in the last round.
+    over: bool,
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        if self.over || self.limit == Some(self.n) {
+            return false;
+        }
+        self.over = self.scenario.round(self.n) == 0;
+        self.n += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.scenario.to_string()
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "round {}, {} empty ground tiles",
+            self.n,
+            self.scenario.empty_ground()
+        )
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        let elves = &self.scenario.elves;
+        match elves.bounds() {
+            Some((min, max)) => {
+                Image::from_bounds(min, max, palette, |p| *elves.get(p).unwrap_or(&Tile::Free))
+            }
+            None => Image::new(0, 0, palette.background()),
+        }
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Tile::KINDS
+    }
+}
+
+/// Reads the input and returns the scenario.
+/// All the rows of the scan must have the same length.
+fn read_input(input: &str) -> Result<Scenario, AocError> {
+    let tiles = Grid::parse(input, Tile::new)?;
+    let elves = tiles.positions(|t| !t.is_free()).map(|p| (p, Tile::Elf));
+    Ok(Scenario {
+        elves: elves.collect(),
+    })
+}
+
+/// Solution of the twenty-third day.
+#[derive(Debug, Clone)]
+pub struct Day23 {
+    /// Initial position of the elves.
+    scenario: Scenario,
+}
+
+impl Day23 {
+    /// Animation of the elves of a given part of the puzzle (the first one stops after 10 rounds).
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        Box::new(Animation {
+            scenario: self.scenario.clone(),
+            n: 0,
+            limit: (part == 1).then_some(10),
+            over: false,
+        })
+    }
+}
+
+impl Solution for Day23 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Empty ground tiles after 10 rounds.
+    fn part1(&self) -> Result<usize, AocError> {
+        let mut scenario = self.scenario.clone();
+        scenario.run_until(10);
+        Ok(scenario.empty_ground())
+    }
+
+    /// First round in which no elf moves.
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self.scenario.clone().run())
+    }
+}
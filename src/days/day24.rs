@@ -0,0 +1,330 @@
+//! Day 24: Blizzard Basin.
+
+use crate::export::{Image, Paint, Palette, Render, Rgb, BACKGROUND};
+use crate::geom::{Direction, Point};
+use crate::grid::Grid;
+use crate::search::bfs;
+use crate::viz::{Scene, Visualize};
+use crate::{AocError, Solution};
+use num::integer::lcm;
+
+#[derive(Debug, Clone)]
+struct Scenario {
+    /// Initial position of the blizzards that go towards every direction (see `Direction::ALL`).
+    /// Grids only cover the inside of the valley (i.e., without walls).
+    blizzards: [Grid<bool>; 4],
+    /// Minutes after which the blizzards are back to their initial position.
+    period: usize,
+}
+
+impl Scenario {
+    /// Number of rows of the valley (without walls).
+    fn n_rows(&self) -> usize {
+        self.blizzards[0].n_rows()
+    }
+
+    /// Number of columns of the valley (without walls).
+    fn n_cols(&self) -> usize {
+        self.blizzards[0].n_cols()
+    }
+
+    /// Location of the entrance of the valley.
+    fn entrance(&self) -> Point {
+        Point::new(0, -1)
+    }
+
+    /// Location of the exit of the valley.
+    fn exit(&self) -> Point {
+        Point::new(self.n_cols() as i32 - 1, self.n_rows() as i32)
+    }
+
+    /// It returns the minimum time at which we can reach `target` if we leave `origin` at `t_0`.
+    fn min_t(&self, origin: Point, target: Point, t_0: usize) -> Option<usize> {
+        // The route has one location per minute, including the origin
+        self.route(origin, target, t_0)
+            .map(|route| t_0 + route.len() - 1)
+    }
+
+    /// It returns the fastest route from `origin` to `target` if we leave `origin` at `t_0`.
+    /// The route contains our location at every minute, from `t_0` until we reach `target`.
+    fn route(&self, origin: Point, target: Point, t_0: usize) -> Option<Vec<Point>> {
+        // Blizzards repeat themselves, so nodes are (t % period, location)
+        let period = self.period;
+        let search = bfs(
+            [(t_0 % period, origin)],
+            |&(t, prev)| {
+                let t = t + 1;
+                // We can move towards any direction or wait
+                let moves = Direction::ALL.map(Direction::vector);
+                moves
+                    .into_iter()
+                    .map(move |m| prev + m)
+                    .chain([prev])
+                    .filter(move |&location| self.is_valid(t as i32, location))
+                    .map(move |location| (t % period, location))
+            },
+            |&(_, location)| location == target,
+        );
+        let path = search.path(search.target()?)?;
+        Some(path.into_iter().map(|(_, location)| location).collect())
+    }
+
+    /// It returns the directions of the blizzards in a location at time `t`.
+    fn blizzards_at(&self, t: i32, location: Point) -> impl Iterator<Item = Direction> + '_ {
+        Direction::ALL
+            .into_iter()
+            .zip(self.blizzards.iter())
+            .filter(move |(direction, blizzards)| {
+                // We guess where should a blizzard be at time 0
+                *blizzards.get_wrapping(location - direction.vector() * t)
+            })
+            .map(|(direction, _)| direction)
+    }
+
+    fn is_valid(&self, t: i32, location: Point) -> bool {
+        if location == self.entrance() || location == self.exit() {
+            // the origin and target locations are always valid.
+            return true;
+        }
+        if !self.blizzards[0].contains(location) {
+            // the location is out of bounds
+            return false;
+        }
+        // We check that there is no blizzard in the location at that time
+        self.blizzards_at(t, location).next().is_none()
+    }
+}
+
+/// What we see in every cell of the valley.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Ground,
+    /// Blizzards in the cell (with the direction of the first one).
+    Blizzard(Direction, usize),
+    Expedition,
+}
+
+impl Cell {
+    /// Symbol of the cell in the terminal (as in the puzzle statement).
+    fn symbol(self) -> char {
+        match self {
+            Cell::Wall => '#',
+            Cell::Ground => '.',
+            Cell::Blizzard(direction, 1) => direction.arrow(),
+            Cell::Blizzard(_, n) => char::from_digit(n as u32, 10).unwrap_or('?'),
+            Cell::Expedition => 'E',
+        }
+    }
+}
+
+impl Paint for Cell {
+    const KINDS: &'static [&'static str] = &["wall", "ground", "blizzard", "expedition"];
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Cell::Wall => "wall",
+            Cell::Ground => "ground",
+            Cell::Blizzard(..) => "blizzard",
+            Cell::Expedition => "expedition",
+        }
+    }
+
+    /// Cells with more blizzards are brighter.
+    fn color(&self) -> Rgb {
+        match *self {
+            Cell::Wall => [0x66, 0x66, 0x66],
+            Cell::Ground => BACKGROUND,
+            Cell::Blizzard(_, n) => {
+                let c = 0x60 + 0x28 * n.min(4) as u8;
+                [c, c, 0xff]
+            }
+            Cell::Expedition => [0x00, 0xcc, 0x00],
+        }
+    }
+}
+
+/// Animation of the expedition, one minute at a time.
+struct Animation {
+    scenario: Scenario,
+    /// Location of the expedition at every minute.
+    route: Vec<Point>,
+    /// Current minute.
+    t: usize,
+}
+
+impl Animation {
+    /// It returns the cell of a point. Points are relative to the inside of the valley.
+    fn cell(&self, point: Point) -> Cell {
+        let scenario = &self.scenario;
+        if self.route.get(self.t) == Some(&point) {
+            return Cell::Expedition;
+        }
+        if point == scenario.entrance() || point == scenario.exit() {
+            return Cell::Ground;
+        }
+        if !scenario.blizzards[0].contains(point) {
+            return Cell::Wall;
+        }
+        let mut blizzards = scenario.blizzards_at(self.t as i32, point);
+        match blizzards.next() {
+            Some(direction) => Cell::Blizzard(direction, 1 + blizzards.count()),
+            None => Cell::Ground,
+        }
+    }
+
+    /// Upper-left corner of the valley (including the walls).
+    fn corner(&self) -> Point {
+        Point::new(-1, -1)
+    }
+}
+
+impl Visualize for Animation {
+    fn step(&mut self) -> bool {
+        match self.t + 1 < self.route.len() {
+            true => {
+                self.t += 1;
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn frame(&self) -> String {
+        let corner = self.corner();
+        let mut res = String::new();
+        for y in 0..self.scenario.n_rows() as i32 + 2 {
+            for x in 0..self.scenario.n_cols() as i32 + 2 {
+                res.push(self.cell(corner + Point::new(x, y)).symbol());
+            }
+            res.push('\n');
+        }
+        res
+    }
+
+    fn status(&self) -> String {
+        match self.route.is_empty() {
+            true => "unable to reach the exit".to_string(),
+            false => format!("minute {}/{}", self.t, self.route.len() - 1),
+        }
+    }
+}
+
+impl Render for Animation {
+    fn render(&self, palette: &Palette) -> Image {
+        let (width, height) = (self.scenario.n_cols() + 2, self.scenario.n_rows() + 2);
+        Image::from_fn(self.corner(), width, height, palette, |p| self.cell(p))
+    }
+
+    fn kinds(&self) -> &'static [&'static str] {
+        Cell::KINDS
+    }
+}
+
+/// Reads the input and returns the scenario.
+/// The valley must be a rectangle surrounded by walls.
+fn read_input(input: &str) -> Result<Scenario, AocError> {
+    let valley = Grid::parse(input, |c| match c {
+        '#' | '.' => Some(c),
+        _ => Direction::from_arrow(c).map(|_| c),
+    })?;
+    if valley.n_rows() < 3 || valley.n_cols() < 3 {
+        return Err(AocError::unexpected_end(
+            valley.n_rows(),
+            "a valley surrounded by walls",
+        ));
+    }
+    let (n_rows, n_cols) = (valley.n_rows() - 2, valley.n_cols() - 2);
+
+    let mut blizzards = [(); 4].map(|_| Grid::new(n_rows, n_cols, false));
+    for (point, &item) in valley.iter() {
+        // Walls surround the valley, and blizzards can only be within the walls
+        let (i, j) = (point.y as usize, point.x as usize);
+        let border = i == 0 || i == n_rows + 1 || j == 0 || j == n_cols + 1;
+        match (border, item, Direction::from_arrow(item)) {
+            (true, '#', _) | (_, '.', _) => {}
+            (false, _, Some(direction)) => {
+                // Variants of Direction are sorted as in Direction::ALL
+                blizzards[direction as usize][point - Point::new(1, 1)] = true;
+            }
+            _ => return Err(AocError::unexpected_char(i + 1, j + 1, item)),
+        }
+    }
+    // Horizontal blizzards come back every n_cols minutes, and vertical ones every n_rows minutes.
+    // Directions without blizzards do not matter, so the valley may repeat sooner than the lcm
+    let moving = |d: Direction| blizzards[d as usize].iter().any(|(_, &b)| b);
+    let horizontal = match moving(Direction::Left) || moving(Direction::Right) {
+        true => n_cols,
+        false => 1,
+    };
+    let vertical = match moving(Direction::Up) || moving(Direction::Down) {
+        true => n_rows,
+        false => 1,
+    };
+    let period = lcm(horizontal, vertical);
+    Ok(Scenario { blizzards, period })
+}
+
+/// Solution of the twenty-fourth day.
+#[derive(Debug)]
+pub struct Day24 {
+    /// Valley with the initial position of the blizzards.
+    scenario: Scenario,
+}
+
+impl Day24 {
+    /// Animation of the expedition of a given part of the puzzle.
+    /// In the second part, the expedition goes back for the snacks and then exits again.
+    pub fn animation(&self, part: usize) -> Box<dyn Scene> {
+        let scenario = &self.scenario;
+        let (entrance, exit) = (scenario.entrance(), scenario.exit());
+        let trips = match part {
+            1 => vec![(entrance, exit)],
+            _ => vec![(entrance, exit), (exit, entrance), (entrance, exit)],
+        };
+        let mut route: Vec<Point> = Vec::new();
+        for (origin, target) in trips {
+            // Every trip starts where the previous one ends, so we skip its first location
+            let t_0 = route.len().saturating_sub(1);
+            match scenario.route(origin, target, t_0) {
+                Some(trip) => route.extend(trip.into_iter().skip(t_0.min(1))),
+                None => break,
+            }
+        }
+        Box::new(Animation {
+            scenario: scenario.clone(),
+            route,
+            t: 0,
+        })
+    }
+}
+
+impl Solution for Day24 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            scenario: read_input(input)?,
+        })
+    }
+
+    /// Fewest minutes required to reach the exit.
+    fn part1(&self) -> Result<usize, AocError> {
+        let scenario = &self.scenario;
+        scenario
+            .min_t(scenario.entrance(), scenario.exit(), 0)
+            .ok_or_else(|| AocError::no_solution("unable to reach the exit"))
+    }
+
+    /// Fewest minutes required to reach the exit, go back for the snacks, and exit again.
+    fn part2(&self) -> Result<usize, AocError> {
+        let scenario = &self.scenario;
+        let (entrance, exit) = (scenario.entrance(), scenario.exit());
+        scenario
+            .min_t(entrance, exit, 0)
+            .and_then(|t| scenario.min_t(exit, entrance, t))
+            .and_then(|t| scenario.min_t(entrance, exit, t))
+            .ok_or_else(|| AocError::no_solution("unable to reach the exit"))
+    }
+}
@@ -0,0 +1,105 @@
+//! Day 25: Full of Hot Air.
+
+use crate::{AocError, Solution};
+
+/// Reads the input and returns the list of numbers.
+pub fn read_input(input: &str) -> Result<Vec<i64>, AocError> {
+    let mut res = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let mut num: i64 = 0;
+        for (j, char) in line.trim_end().chars().enumerate() {
+            let digit = match char {
+                '0'..='2' => char as i64 - '0' as i64,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(AocError::unexpected_char(i + 1, j + 1, char)),
+            };
+            num = num
+                .checked_mul(5)
+                .and_then(|n| n.checked_add(digit))
+                .ok_or_else(|| AocError::parse(i + 1, 1, "the number is too big"))?;
+        }
+        res.push(num);
+    }
+    Ok(res)
+}
+
+/// It returns the SNAFU representation of a non-negative number.
+pub fn dec_to_snafu(mut num: i64) -> String {
+    // First, we translate the number to a 5 base
+    let mut numeric = Vec::new();
+    while num != 0 {
+        numeric.push(num % 5);
+        num /= 5;
+    }
+    numeric.push(0); // We add an extra digit for convenience in case of an overflow
+                     // Now it is time to modify the base to snafu
+    for i in 0..numeric.len() - 1 {
+        // First, we correct any potential overflow due to carries
+        while numeric[i] >= 5 {
+            numeric[i] -= 5;
+            numeric[i + 1] += 1;
+        }
+        // Next, we change the digit to negative values if needed
+        if numeric[i] >= 3 {
+            numeric[i + 1] += 1; // We need to add 1 to the next digit!
+            numeric[i] = match numeric[i] {
+                3 => -2,
+                4 => -1,
+                _ => panic!("this should never happen"),
+            };
+        }
+        assert!(numeric[numeric.len() - 1] < 3); // Just checking the the last digit is correct
+    }
+    // Finally, we iterate in reverse order to add the digit in the string
+    let mut res = String::new();
+    for &digit in numeric.iter().rev() {
+        res.push(match digit {
+            -2 => '=',
+            -1 => '-',
+            n => char::from_digit(n as u32, 5).unwrap(),
+        })
+    }
+    // We trim the starting zeros just in case (but 0 is still 0)
+    match res.trim_start_matches('0') {
+        "" => String::from("0"),
+        trimmed => String::from(trimmed),
+    }
+}
+
+/// Solution of the twenty-fifth day.
+#[derive(Debug, Clone)]
+pub struct Day25 {
+    /// Fuel requirements in decimal.
+    numbers: Vec<i64>,
+}
+
+impl Solution for Day25 {
+    type Answer1 = String;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            numbers: read_input(input)?,
+        })
+    }
+
+    /// SNAFU number to supply to Bob's console.
+    fn part1(&self) -> Result<String, AocError> {
+        let sum = self
+            .numbers
+            .iter()
+            .try_fold(0i64, |acc, &n| acc.checked_add(n))
+            .ok_or_else(|| AocError::no_solution("the sum is too big"))?;
+        // My conversion only works with positive numbers
+        match sum >= 0 {
+            true => Ok(dec_to_snafu(sum)),
+            false => Err(AocError::no_solution("the sum is negative")),
+        }
+    }
+
+    /// There is no second exercise on Christmas day: we just have to start the blender.
+    fn part2(&self) -> Result<&'static str, AocError> {
+        Ok("Merry Christmas!")
+    }
+}
@@ -0,0 +1,31 @@
+//! Diagnostics of the solvers (e.g., the state of the monkeys of day 11 after some rounds).
+//!
+//! Diagnostics never go to stdout, which is reserved for the answers.
+//! They are suppressed by default, and [`set_verbose`] sends them to stderr.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// If true, diagnostics are printed to stderr.
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// It enables or disables the diagnostics of the solvers.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+/// It returns true if the diagnostics of the solvers are enabled.
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// It prints a diagnostic message to stderr if diagnostics are enabled.
+/// Arguments are not even formatted otherwise, so expensive dumps are free when suppressed.
+macro_rules! diag {
+    ($($arg:tt)*) => {
+        if $crate::diag::is_verbose() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use diag;
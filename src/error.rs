@@ -0,0 +1,182 @@
+//! Error type shared by all the solutions.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Errors that may occur while parsing a puzzle input or solving a puzzle.
+/// Lines and columns are 1-based, so they match what you see in your text editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input contains a character that does not belong to the puzzle format.
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// The input does not follow the puzzle format.
+    Parse {
+        line: usize,
+        column: usize,
+        msg: String,
+    },
+    /// The input ended before something that we expected.
+    UnexpectedEnd { line: usize, expected: String },
+    /// The input is well-formed, but the puzzle has no solution for it.
+    NoSolution(String),
+    /// There is no puzzle for the requested day and part.
+    UnknownPuzzle { day: usize, part: usize },
+    /// A solution was created with parameters that make no sense (e.g., groups of zero elves).
+    InvalidArgument(String),
+}
+
+impl AocError {
+    /// Creates a new parsing error.
+    pub fn parse(line: usize, column: usize, msg: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column,
+            msg: msg.into(),
+        }
+    }
+
+    /// Creates a new error for an unexpected character.
+    pub fn unexpected_char(line: usize, column: usize, found: char) -> Self {
+        Self::UnexpectedChar {
+            line,
+            column,
+            found,
+        }
+    }
+
+    /// Creates a new error for an input that ended too early.
+    pub fn unexpected_end(line: usize, expected: impl Into<String>) -> Self {
+        Self::UnexpectedEnd {
+            line,
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates a new error for puzzles without solution.
+    pub fn no_solution(msg: impl Into<String>) -> Self {
+        Self::NoSolution(msg.into())
+    }
+
+    /// Creates a new error for invalid parameters of a solution.
+    pub fn invalid_argument(msg: impl Into<String>) -> Self {
+        Self::InvalidArgument(msg.into())
+    }
+
+    /// It moves the location of the error `offset` lines down.
+    /// Parsers of single lines report errors in line 1, so the caller must fix the line number.
+    pub fn offset_lines(mut self, offset: usize) -> Self {
+        match &mut self {
+            Self::UnexpectedChar { line, .. }
+            | Self::Parse { line, .. }
+            | Self::UnexpectedEnd { line, .. } => *line += offset,
+            Self::NoSolution(_) | Self::UnknownPuzzle { .. } | Self::InvalidArgument(_) => {}
+        }
+        self
+    }
+
+    /// It moves the location of the error `offset` columns to the right.
+    /// Useful when a token is parsed on its own and then the error is reported for the full line.
+    pub fn offset_columns(mut self, offset: usize) -> Self {
+        match &mut self {
+            Self::UnexpectedChar { column, .. } | Self::Parse { column, .. } => *column += offset,
+            Self::UnexpectedEnd { .. }
+            | Self::NoSolution(_)
+            | Self::UnknownPuzzle { .. }
+            | Self::InvalidArgument(_) => {}
+        }
+        self
+    }
+
+    /// It moves an error that was reported relative to `token` so it is relative to `text`.
+    /// `token` must be a subslice of `text`.
+    pub(crate) fn within(self, text: &str, token: &str) -> Self {
+        let (line, column) = location_of(text, token);
+        // Only errors in the first line of the token are shifted to the right
+        let res = match self {
+            Self::UnexpectedChar { line: 1, .. } | Self::Parse { line: 1, .. } => {
+                self.offset_columns(column - 1)
+            }
+            _ => self,
+        };
+        res.offset_lines(line - 1)
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {}, column {}: unexpected character {:?}",
+                line, column, found
+            ),
+            Self::Parse { line, column, msg } => {
+                write!(f, "line {}, column {}: {}", line, column, msg)
+            }
+            Self::UnexpectedEnd { line, expected } => {
+                write!(
+                    f,
+                    "line {}: unexpected end of input, expected {}",
+                    line, expected
+                )
+            }
+            Self::NoSolution(msg) => write!(f, "no solution: {}", msg),
+            Self::UnknownPuzzle { day, part } => {
+                write!(f, "there is no puzzle for day {} part {}", day, part)
+            }
+            Self::InvalidArgument(msg) => write!(f, "invalid argument: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// It returns the 1-based column where `token` starts within `line`.
+/// `token` must be a subslice of `line`. Otherwise, it returns 1.
+pub(crate) fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    match line.get(..offset) {
+        Some(prefix) => prefix.chars().count() + 1,
+        None => 1,
+    }
+}
+
+/// It returns the 1-based line and column where `token` starts within a multi-line `text`.
+/// `token` must be a subslice of `text`. Otherwise, it returns (1, 1).
+pub(crate) fn location_of(text: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    match text.get(..offset) {
+        Some(prefix) => {
+            let line_start = prefix.rfind('\n').map_or(0, |i| i + 1);
+            (
+                prefix.matches('\n').count() + 1,
+                prefix[line_start..].chars().count() + 1,
+            )
+        }
+        None => (1, 1),
+    }
+}
+
+/// It parses a `token` located within the `line_i`th line (1-based) of the input.
+/// If it fails, the error points to the token.
+pub(crate) fn parse_token<T: FromStr>(
+    line_i: usize,
+    line: &str,
+    token: &str,
+) -> Result<T, AocError> {
+    token.parse::<T>().map_err(|_| {
+        AocError::parse(
+            line_i,
+            column_of(line, token),
+            format!("invalid {} {:?}", std::any::type_name::<T>(), token),
+        )
+    })
+}
@@ -0,0 +1,403 @@
+//! Pictures of the simulations, so we can show them off in our write-ups.
+//!
+//! Scenarios that implement [`Render`] draw themselves as an [`Image`] with one pixel per cell.
+//! Cells implement [`Paint`] to choose their colour, and a [`Palette`] may override the colour
+//! of every kind of cell (e.g., `sand=#ffcc00,rock=#884400`). Images are saved as PNG files,
+//! and sequences of images are encoded one by one in animated GIFs with a [`GifWriter`].
+
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::AocError;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// Colour of a pixel (red, green, and blue).
+pub type Rgb = [u8; 3];
+
+/// Background of the Advent of Code website. It fills the gaps of pictures with different sizes.
+pub const BACKGROUND: Rgb = [0x0f, 0x0f, 0x23];
+
+/// Cells that can be drawn in a picture.
+pub trait Paint {
+    /// Every kind of cell, so palettes can reject the kinds that no cell has.
+    const KINDS: &'static [&'static str];
+
+    /// Name of the kind of cell (e.g., `sand`). Palettes assign colours to kinds of cells.
+    fn kind(&self) -> &'static str;
+
+    /// Default colour of the cell.
+    fn color(&self) -> Rgb;
+}
+
+/// Colours of the kinds of cells. Kinds without colour keep their default colour.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: HashMap<String, Rgb>,
+}
+
+impl Palette {
+    /// It creates an empty palette (i.e., every cell keeps its default colour).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// It parses a palette with the format `kind=#rrggbb,kind=#rrggbb,...`.
+    /// Only the `background` and the `kinds` of the cells that we paint are valid kinds.
+    pub fn parse(s: &str, kinds: &[&str]) -> Result<Self, AocError> {
+        let mut res = Self::new();
+        let mut column = 1;
+        for entry in s.split(',') {
+            let (kind, color) = entry.split_once('=').ok_or_else(|| {
+                AocError::parse(
+                    1,
+                    column,
+                    format!("expected kind=#rrggbb, found {:?}", entry),
+                )
+            })?;
+            if kind.trim() != "background" && !kinds.contains(&kind.trim()) {
+                let valid: Vec<_> = ["background"].iter().chain(kinds).copied().collect();
+                return Err(AocError::parse(
+                    1,
+                    column,
+                    format!(
+                        "unknown kind {:?} (expected one of: {})",
+                        kind.trim(),
+                        valid.join(", ")
+                    ),
+                ));
+            }
+            let color = parse_color(color).ok_or_else(|| {
+                let column = column + kind.chars().count() + 1;
+                AocError::parse(1, column, format!("invalid color {:?}", color))
+            })?;
+            res.set(kind.trim(), color);
+            column += entry.chars().count() + 1;
+        }
+        Ok(res)
+    }
+
+    /// It sets the colour of a kind of cell.
+    pub fn set(&mut self, kind: &str, color: Rgb) {
+        self.colors.insert(kind.to_string(), color);
+    }
+
+    /// It returns the colour of a cell.
+    pub fn color(&self, cell: &impl Paint) -> Rgb {
+        self.colors
+            .get(cell.kind())
+            .copied()
+            .unwrap_or_else(|| cell.color())
+    }
+
+    /// It returns the colour of the background (see [`BACKGROUND`]).
+    /// It can be changed with the `background` kind.
+    pub fn background(&self) -> Rgb {
+        self.colors.get("background").copied().unwrap_or(BACKGROUND)
+    }
+}
+
+/// It parses a colour with the format `#rrggbb` (the `#` is optional).
+pub fn parse_color(s: &str) -> Option<Rgb> {
+    let s = s.trim();
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok();
+    Some([channel(0)?, channel(1)?, channel(2)?])
+}
+
+/// A picture, stored pixel by pixel and row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// It creates an image filled with a given colour.
+    pub fn new(width: usize, height: usize, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    /// It draws a grid with one pixel per cell.
+    pub fn from_grid<T: Paint>(grid: &Grid<T>, palette: &Palette) -> Self {
+        Self {
+            width: grid.n_cols(),
+            height: grid.n_rows(),
+            pixels: grid.iter().map(|(_, cell)| palette.color(cell)).collect(),
+        }
+    }
+
+    /// It draws a rectangle of cells with one pixel per cell.
+    /// `cell` returns the cell of every point, from `corner` to `corner + (width, height)`.
+    pub fn from_fn<T: Paint>(
+        corner: Point,
+        width: usize,
+        height: usize,
+        palette: &Palette,
+        mut cell: impl FnMut(Point) -> T,
+    ) -> Self {
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                pixels.push(palette.color(&cell(corner + Point::new(x, y))));
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// It draws the cells from `min` to `max` (both included) with one pixel per cell.
+    pub fn from_bounds<T: Paint>(
+        min: Point,
+        max: Point,
+        palette: &Palette,
+        cell: impl FnMut(Point) -> T,
+    ) -> Self {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        Self::from_fn(min, width, height, palette, cell)
+    }
+
+    /// Width of the image (in pixels).
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the image (in pixels).
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// It returns the colour of a pixel (if any).
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        match x < self.width && y < self.height {
+            true => Some(self.pixels[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// It returns a copy of the image with every pixel turned into a `factor` × `factor` square.
+    pub fn scale(&self, factor: usize) -> Self {
+        let (width, height) = (self.width * factor, self.height * factor);
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            let row = &self.pixels[y / factor * self.width..(y / factor + 1) * self.width];
+            pixels.extend(row.iter().flat_map(|&p| std::iter::repeat_n(p, factor)));
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// It returns a copy of the image in a bigger canvas filled with `color`.
+    /// The image stays in the upper-left corner. The canvas is never smaller than the image.
+    pub fn pad(&self, width: usize, height: usize, color: Rgb) -> Self {
+        let (width, height) = (width.max(self.width), height.max(self.height));
+        let mut res = Self::new(width, height, color);
+        for y in 0..self.height {
+            let row = &self.pixels[y * self.width..(y + 1) * self.width];
+            res.pixels[y * width..y * width + self.width].copy_from_slice(row);
+        }
+        res
+    }
+
+    /// It encodes the image as a PNG file.
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(self.pixels.as_flattened())
+            .map_err(io::Error::other)
+    }
+}
+
+/// Scenarios that can be drawn as a picture.
+pub trait Render {
+    /// It draws the current state of the scenario with one pixel per cell.
+    fn render(&self, palette: &Palette) -> Image;
+
+    /// Kinds of the cells that it draws (i.e., the [`Paint::KINDS`] of its cells).
+    fn kinds(&self) -> &'static [&'static str];
+}
+
+/// Size and colours of the frames of an animation. We collect them in a first pass over the
+/// frames, so the GIF can be written frame by frame (without keeping them in memory) in a second one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    colors: Vec<Rgb>,
+    index: HashMap<Rgb, u8>,
+    /// It is false if the frames have more than 256 colours (then they cannot share a palette).
+    global: bool,
+}
+
+impl Canvas {
+    /// It creates an empty canvas filled with the `background` colour.
+    pub fn new(background: Rgb) -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            colors: vec![background],
+            index: HashMap::from([(background, 0)]),
+            global: true,
+        }
+    }
+
+    /// It grows the canvas so it fits the image, and adds the colours of the image to the palette.
+    pub fn fit(&mut self, image: &Image) {
+        self.width = self.width.max(image.width);
+        self.height = self.height.max(image.height);
+        for pixel in &image.pixels {
+            if self.global && !self.index.contains_key(pixel) {
+                match self.colors.len() < 256 {
+                    true => {
+                        self.index.insert(*pixel, self.colors.len() as u8);
+                        self.colors.push(*pixel);
+                    }
+                    false => self.global = false,
+                }
+            }
+        }
+    }
+
+    /// Width of the canvas (in pixels).
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the canvas (in pixels).
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Colour of the gaps between the canvas and smaller images.
+    pub fn background(&self) -> Rgb {
+        self.colors[0]
+    }
+}
+
+/// Encoder of animated GIFs that loop forever. Frames are encoded as soon as we get them,
+/// so long animations do not need to fit in memory.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    canvas: Canvas,
+    scale: usize,
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    /// It writes the header of an animation with the size and colours of a [`Canvas`].
+    /// Every pixel of the frames becomes a `scale` × `scale` square, and every frame is shown
+    /// `delay` hundredths of a second.
+    pub fn new(out: W, canvas: Canvas, scale: usize, delay: u16) -> io::Result<Self> {
+        let (w, h) = (
+            u16::try_from(canvas.width * scale)
+                .map_err(|_| io::Error::other("the animation is too wide"))?,
+            u16::try_from(canvas.height * scale)
+                .map_err(|_| io::Error::other("the animation is too tall"))?,
+        );
+        // Pictures of the simulations have few colours, so we can usually share a global palette
+        let palette: Vec<u8> = match canvas.global {
+            true => canvas.colors.as_flattened().to_vec(),
+            false => Vec::new(),
+        };
+        let mut encoder = gif::Encoder::new(out, w, h, &palette).map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        Ok(Self {
+            encoder,
+            canvas,
+            scale,
+            delay,
+        })
+    }
+
+    /// It encodes the next frame. The image must fit in the canvas (smaller images are padded
+    /// with its background), and its colours must be in the canvas too.
+    pub fn write(&mut self, image: &Image) -> io::Result<()> {
+        let canvas = &self.canvas;
+        if image.width > canvas.width || image.height > canvas.height {
+            return Err(io::Error::other("the frame does not fit in the animation"));
+        }
+        let (width, height) = (canvas.width * self.scale, canvas.height * self.scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        let mut row = Vec::with_capacity(width);
+        for y in 0..canvas.height {
+            row.clear();
+            for x in 0..canvas.width {
+                let pixel = image.get(x, y).unwrap_or(canvas.background());
+                row.extend(std::iter::repeat_n(pixel, self.scale));
+            }
+            for _ in 0..self.scale {
+                pixels.extend_from_slice(&row);
+            }
+        }
+        let (w, h) = (width as u16, height as u16);
+        let mut frame = match canvas.global {
+            true => {
+                let pixels = pixels
+                    .iter()
+                    .map(|p| canvas.index.get(p).copied())
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| io::Error::other("the frame has colours out of the palette"))?;
+                gif::Frame::from_indexed_pixels(w, h, pixels, None)
+            }
+            // Otherwise, every frame gets its own palette
+            false => gif::Frame::from_rgb(w, h, pixels.as_flattened()),
+        };
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame).map_err(io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KINDS: &[&str] = &["air", "rock", "sand"];
+
+    #[test]
+    fn palette_colors() {
+        let palette =
+            Palette::parse("sand=#ffcc00, rock=884400,background=#000000", KINDS).unwrap();
+        let mut expected = Palette::new();
+        expected.set("sand", [0xff, 0xcc, 0x00]);
+        expected.set("rock", [0x88, 0x44, 0x00]);
+        expected.set("background", [0, 0, 0]);
+        assert_eq!(palette, expected);
+        assert_eq!(palette.background(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn palette_errors() {
+        let error = |s| Palette::parse(s, KINDS).unwrap_err().to_string();
+        assert_eq!(
+            error("sand=#ffcc00,water=#0000ff"),
+            "line 1, column 14: unknown kind \"water\" (expected one of: background, air, rock, sand)"
+        );
+        assert_eq!(
+            error("sand=#ffcc00,rock"),
+            "line 1, column 14: expected kind=#rrggbb, found \"rock\""
+        );
+        assert_eq!(
+            error("sand=#ffcc0g"),
+            "line 1, column 6: invalid color \"#ffcc0g\""
+        );
+    }
+}
@@ -0,0 +1,1024 @@
+//! Random puzzle inputs, so we can stress the solvers with more than one input per day.
+//!
+//! Every day has its own generator, which writes an input with the same format as the real ones.
+//! Generators are deterministic: the same seed and size always give the same input, no matter the
+//! platform or the version of any dependency (we use our own [`Rng`]). The `size` scales the input
+//! (e.g., the number of elves of day 1 or the length of the jet pattern of day 17), and every day
+//! documents what it means in [`SIZES`].
+//!
+//! Generated inputs are not just well-formed: they are built so the puzzles have an answer (e.g.,
+//! the map of day 12 always has a path to the exit, the riddle of day 21 always has an integer
+//! solution, and the sensors of day 15 leave exactly one gap for the distress beacon).
+
+use crate::days::day25::dec_to_snafu;
+use crate::{AocError, N_DAYS};
+use num::integer::lcm;
+use num::PrimInt;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::fmt::Write;
+use std::io;
+use std::ops::RangeInclusive;
+
+/// Pseudo-random number generator (SplitMix64). It is not cryptographically secure,
+/// but it is fast, it has a decent quality, and its sequence never changes.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// It creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// It returns the next random number of the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// It returns a random number of an inclusive range. The range must not be empty.
+    pub fn range<T: PrimInt>(&mut self, range: RangeInclusive<T>) -> T {
+        let (start, end) = (range.start().to_i128(), range.end().to_i128());
+        let (start, end) = (start.unwrap(), end.unwrap());
+        assert!(start <= end, "empty range");
+        let len = (end - start + 1) as u128;
+        // Multiplying by the length is faster and less biased than the modulo
+        let offset = (self.next_u64() as u128 * len) >> 64;
+        T::from(start + offset as i128).unwrap()
+    }
+
+    /// It returns an index of a slice of length `len` (which must not be zero).
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len - 1)
+    }
+
+    /// It returns true with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < p
+    }
+
+    /// It returns a random item of a slice (which must not be empty).
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// It shuffles a slice (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Signature of the generators: they write a random input of a given size.
+type Generator = fn(&mut Rng, usize) -> String;
+
+/// Generators of every puzzle, sorted by day.
+const GENERATORS: [Generator; N_DAYS] = [
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25,
+];
+
+/// Default size of the inputs of every day (similar to the real inputs) and what it means.
+pub const SIZES: [(usize, &str); N_DAYS] = [
+    (250, "elves"),
+    (2500, "rounds"),
+    (100, "groups of three elves"),
+    (1000, "pairs of elves"),
+    (500, "moves of the crane"),
+    (4096, "characters of the datastream"),
+    (200, "directories"),
+    (99, "rows and columns of the forest"),
+    (2000, "moves of the head"),
+    (140, "instructions (programs last at least 240 cycles)"),
+    (8, "monkeys (2 to 18)"),
+    (160, "columns of the map (with a quarter of rows)"),
+    (150, "pairs of packets"),
+    (150, "rock paths"),
+    (
+        25,
+        "sensors of the grid that covers the search space (roughly)",
+    ),
+    (60, "valves (at most 64, and 15 of them with flow)"),
+    (10000, "jets"),
+    (2500, "cubes"),
+    (30, "blueprints"),
+    (5000, "numbers (at least 2)"),
+    (2000, "monkeys"),
+    (50, "side of the faces of the cube"),
+    (70, "rows and columns of the grove"),
+    (120, "columns of the valley (with a fifth of rows)"),
+    (120, "SNAFU numbers"),
+];
+
+/// It generates a random input for the puzzle of a given `day` (1 to 25).
+/// If there is no `size`, we use the default size of the day (see [`SIZES`]).
+pub fn generate(day: usize, seed: u64, size: Option<usize>) -> Result<String, AocError> {
+    let i = day
+        .checked_sub(1)
+        .filter(|&i| i < N_DAYS)
+        .ok_or(AocError::UnknownPuzzle { day, part: 1 })?;
+    let size = size.unwrap_or(SIZES[i].0);
+    Ok(GENERATORS[i](&mut rng(day, seed), size))
+}
+
+/// It writes a random input for the puzzle of a given `day` (same as [`generate`]).
+/// Inputs that can be much larger than the available memory (the inventory of day 1) are written
+/// line by line as soon as they are produced. The rest are generated before writing them.
+pub fn generate_to(
+    day: usize,
+    seed: u64,
+    size: Option<usize>,
+    out: &mut impl io::Write,
+) -> io::Result<()> {
+    match day {
+        1 => write_day01(&mut rng(day, seed), size.unwrap_or(SIZES[0].0), out),
+        _ => {
+            let input = generate(day, seed, size).map_err(io::Error::other)?;
+            out.write_all(input.as_bytes())
+        }
+    }
+}
+
+/// It returns the generator of a given day and seed.
+fn rng(day: usize, seed: u64) -> Rng {
+    // Every day gets a different sequence, even with the same seed
+    Rng::new(seed ^ (day as u64).wrapping_mul(0x2545f4914f6cdd1d))
+}
+
+/// It writes every line followed by a new line.
+fn join_lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().fold(String::new(), |mut res, line| {
+        res.push_str(&line);
+        res.push('\n');
+        res
+    })
+}
+
+/// Calories carried by every elf, with blank lines between elves.
+fn day01(rng: &mut Rng, size: usize) -> String {
+    let mut res = Vec::new();
+    write_day01(rng, size, &mut res).expect("writing to a vector never fails");
+    String::from_utf8(res).expect("calories are ASCII")
+}
+
+/// It writes the calories of every elf as soon as they are produced (see [`day01`]).
+fn write_day01(rng: &mut Rng, size: usize, out: &mut impl io::Write) -> io::Result<()> {
+    for elf in 0..size.max(1) {
+        if elf > 0 {
+            writeln!(out)?;
+        }
+        for _ in 0..rng.range(1..=15) {
+            writeln!(out, "{}", rng.range(1000..=60000_u32))?;
+        }
+    }
+    Ok(())
+}
+
+/// Rounds of rock, paper, scissors.
+fn day02(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        format!(
+            "{} {}",
+            rng.pick(&['A', 'B', 'C']),
+            rng.pick(&['X', 'Y', 'Z'])
+        )
+    }))
+}
+
+/// Rucksacks of groups of three elves. Every rucksack has exactly one item in both compartments,
+/// and every group has exactly one item (the badge) in the three rucksacks.
+fn day03(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        rng.shuffle(&mut items);
+        // Elves of the group only share the badge: the rest of items are split among them
+        let badge = items.pop().unwrap();
+        for pool in items.chunks(items.len() / 3) {
+            let mut pool = pool.to_vec();
+            pool.push(badge);
+            rng.shuffle(&mut pool);
+            let common = pool.pop().unwrap();
+            let (left, right) = pool.split_at(pool.len() / 2);
+            let len = rng.range(4..=16);
+            let mut halves = [left, right].map(|side| {
+                let mut half: Vec<char> = (1..len).map(|_| *rng.pick(side)).collect();
+                half.push(common);
+                half
+            });
+            // The badge must be in the rucksack, even if it is not the common item
+            if badge != common {
+                let side = usize::from(right.contains(&badge));
+                halves[side][0] = badge;
+            }
+            for half in halves.iter_mut() {
+                rng.shuffle(half);
+            }
+            lines.push(halves.concat().into_iter().collect());
+        }
+    }
+    join_lines(lines)
+}
+
+/// Pairs of ranges of sections.
+fn day04(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.range(1..=99_u32);
+        format!("{}-{}", start, rng.range(start..=99))
+    };
+    join_lines((0..size).map(|_| format!("{},{}", range(), range())))
+}
+
+/// Stacks of crates and moves of the crane. Moves never take all the crates of a stack,
+/// so there is always a crate on top of every stack.
+fn day05(rng: &mut Rng, size: usize) -> String {
+    const N_STACKS: usize = 9;
+    let mut stacks: Vec<Vec<char>> = (0..N_STACKS)
+        .map(|_| {
+            (0..rng.range(2..=8))
+                .map(|_| rng.range(b'A'..=b'Z') as char)
+                .collect()
+        })
+        .collect();
+    let mut res = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    for level in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        res.push_str(row.join(" ").trim_end());
+        res.push('\n');
+    }
+    let indices: Vec<String> = (1..=N_STACKS).map(|i| format!(" {} ", i)).collect();
+    writeln!(res, "{}\n", indices.join(" ")).unwrap();
+    for _ in 0..size {
+        let tall: Vec<usize> = (0..N_STACKS).filter(|&i| stacks[i].len() > 1).collect();
+        let from = *rng.pick(&tall);
+        let to = (from + rng.range(1..=N_STACKS - 1)) % N_STACKS;
+        let n = rng.range(1..=(stacks[from].len() - 1).min(10));
+        let height = stacks[from].len();
+        let crates = stacks[from].split_off(height - n);
+        stacks[to].extend(crates);
+        writeln!(res, "move {} from {} to {}", n, from + 1, to + 1).unwrap();
+    }
+    res
+}
+
+/// Datastream with a few repeated characters. Somewhere in its second half there is a
+/// message marker (fourteen different characters), so both parts have an answer.
+fn day06(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(28);
+    let alphabet: Vec<char> = ('a'..='z').collect();
+    // A small alphabet makes markers rare
+    let mut res: Vec<char> = (0..size).map(|_| alphabet[rng.index(8)]).collect();
+    let mut marker = alphabet.clone();
+    rng.shuffle(&mut marker);
+    let start = rng.range(size / 2..=size - 14);
+    res[start..start + 14].copy_from_slice(&marker[..14]);
+    res.into_iter().chain(['\n']).collect()
+}
+
+/// Terminal output of a tour of a random filesystem. The disk is always big enough for it.
+fn day07(rng: &mut Rng, size: usize) -> String {
+    /// A directory: its subdirectories (indices) and its files (names and sizes).
+    struct Dir {
+        name: String,
+        dirs: Vec<usize>,
+        files: Vec<(String, usize)>,
+    }
+    let name = |rng: &mut Rng| -> String {
+        let len = rng.range(1..=8);
+        (0..len).map(|_| rng.range(b'a'..=b'z') as char).collect()
+    };
+    let mut dirs = vec![Dir {
+        name: "/".to_string(),
+        dirs: Vec::new(),
+        files: Vec::new(),
+    }];
+    for i in 1..size.max(1) {
+        let parent = rng.index(i);
+        dirs[parent].dirs.push(i);
+        dirs.push(Dir {
+            name: format!("{}{}", name(rng), i),
+            dirs: Vec::new(),
+            files: Vec::new(),
+        });
+    }
+    // The files use around 50000000 of the 70000000 units of the disk, so we must free some space
+    let mean = 50_000_000 / (2 * dirs.len() + 1);
+    let mut total = 0;
+    for dir in dirs.iter_mut() {
+        for _ in 0..rng.range(0..=4) {
+            let size = rng.range(mean / 10..=mean * 19 / 10);
+            let extension = rng.pick(&["", ".txt", ".dat", ".lst"]);
+            dir.files
+                .push((format!("{}{}", name(rng), extension), size));
+            total += size;
+        }
+    }
+    if total > 65_000_000 {
+        for (_, size) in dirs.iter_mut().flat_map(|d| d.files.iter_mut()) {
+            *size = *size * 50_000_000 / total;
+        }
+    }
+    // We list every directory depth-first
+    fn tour(dirs: &[Dir], i: usize, res: &mut String) {
+        let dir = &dirs[i];
+        writeln!(res, "$ cd {}\n$ ls", dir.name).unwrap();
+        for &sub in dir.dirs.iter() {
+            writeln!(res, "dir {}", dirs[sub].name).unwrap();
+        }
+        for (name, size) in dir.files.iter() {
+            writeln!(res, "{} {}", size, name).unwrap();
+        }
+        for &sub in dir.dirs.iter() {
+            tour(dirs, sub, res);
+            res.push_str("$ cd ..\n");
+        }
+    }
+    let mut res = String::new();
+    tour(&dirs, 0, &mut res);
+    res
+}
+
+/// It writes a grid of random characters with `n_rows` and `n_cols`.
+fn grid(n_rows: usize, n_cols: usize, mut cell: impl FnMut() -> char) -> String {
+    join_lines((0..n_rows).map(|_| (0..n_cols).map(|_| cell()).collect()))
+}
+
+/// Heights of the trees of a square forest.
+fn day08(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    grid(size, size, || {
+        char::from_digit(rng.range(0..=9), 10).unwrap()
+    })
+}
+
+/// Moves of the head of the rope.
+fn day09(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size).map(|_| {
+        format!(
+            "{} {}",
+            rng.pick(&['U', 'D', 'L', 'R']),
+            rng.range(1..=20_u32)
+        )
+    }))
+}
+
+/// Program of the CPU. It lasts at least 240 cycles, so the CRT draws the whole screen.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    let (mut lines, mut cycles, mut x) = (Vec::new(), 0, 1_i32);
+    while lines.len() < size || cycles < 240 {
+        match rng.chance(0.3) {
+            true => {
+                lines.push("noop".to_string());
+                cycles += 1;
+            }
+            false => {
+                // We keep the sprite close to the screen, as real programs do
+                let dx = match !(0..=39).contains(&x) {
+                    true => (20 - x).signum() * rng.range(1..=10),
+                    false => rng.range(-10..=10),
+                };
+                x += dx;
+                lines.push(format!("addx {}", dx));
+                cycles += 2;
+            }
+        }
+    }
+    join_lines(lines)
+}
+
+/// Worry operation of a monkey of day 11.
+#[derive(Debug, Clone, Copy)]
+enum Worry {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Worry {
+    /// New worry level, or `None` if it overflows.
+    fn apply(self, old: u64) -> Option<u64> {
+        match self {
+            Worry::Add(n) => old.checked_add(n),
+            Worry::Multiply(n) => old.checked_mul(n),
+            Worry::Square => old.checked_mul(old),
+        }
+    }
+}
+
+/// Attempts to find notes of day 11 that do not overflow before giving up on multiplications.
+const DAY11_ATTEMPTS: usize = 100;
+
+/// Notes about the monkeys. We only keep the notes whose worry levels never overflow
+/// in the first part. After [`DAY11_ATTEMPTS`] attempts, monkeys only add to the worry levels
+/// (e.g., with two monkeys, every item goes through every monkey in every round, so worry
+/// levels grow too fast if they multiply).
+fn day11(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    let n_monkeys = size.clamp(2, 2 * PRIMES.len());
+    let mut divisors = PRIMES.repeat(2)[..n_monkeys].to_vec();
+    // In the second part, the square of any worry level must fit after reducing it
+    // Games of two monkeys would square every item in every round
+    let squares = divisors.iter().product::<u64>() < 1 << 32 && n_monkeys > 2;
+    for attempt in 0.. {
+        let only_add = attempt >= DAY11_ATTEMPTS;
+        rng.shuffle(&mut divisors);
+        let square = rng.index(n_monkeys);
+        let other = |rng: &mut Rng, i: usize| (i + rng.range(1..=n_monkeys - 1)) % n_monkeys;
+        let monkeys: Vec<(Vec<u64>, Worry, usize, usize)> = (0..n_monkeys)
+            .map(|i| {
+                let items = (0..rng.range(1..=8)).map(|_| rng.range(50..=99)).collect();
+                // Most monkeys add something; a few multiply (and one squares)
+                let worry = match (i == square && squares, rng.chance(0.25)) {
+                    _ if only_add => Worry::Add(rng.range(1..=8)),
+                    (true, _) => Worry::Square,
+                    (false, true) => Worry::Multiply(rng.range(2..=19)),
+                    (false, false) => Worry::Add(rng.range(1..=8)),
+                };
+                let if_true = other(rng, i);
+                let mut if_false = other(rng, i);
+                while if_false == if_true && n_monkeys > 2 {
+                    if_false = other(rng, i);
+                }
+                (items, worry, if_true, if_false)
+            })
+            .collect();
+        // We play the first part with checked arithmetic
+        let mut objects: Vec<Vec<u64>> = monkeys.iter().map(|m| m.0.clone()).collect();
+        let mut overflow = false;
+        for _ in 0..20 {
+            for (i, &(_, worry, if_true, if_false)) in monkeys.iter().enumerate() {
+                for old in std::mem::take(&mut objects[i]) {
+                    match worry.apply(old) {
+                        Some(new) if (new / 3) % divisors[i] == 0 => objects[if_true].push(new / 3),
+                        Some(new) => objects[if_false].push(new / 3),
+                        None => overflow = true,
+                    }
+                }
+            }
+        }
+        if overflow {
+            continue;
+        }
+        let notes: Vec<String> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, (items, worry, if_true, if_false))| {
+                let items: Vec<String> = items.iter().map(|w| w.to_string()).collect();
+                let operation = match worry {
+                    Worry::Add(n) => format!("old + {}", n),
+                    Worry::Multiply(n) => format!("old * {}", n),
+                    Worry::Square => "old * old".to_string(),
+                };
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                    i,
+                    items.join(", "),
+                    operation,
+                    divisors[i],
+                    if_true,
+                    if_false
+                )
+            })
+            .collect();
+        return notes.join("\n");
+    }
+    unreachable!("additions never overflow in 20 rounds")
+}
+
+/// Height map with a trail from the start to the exit, so there is always a path.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let n_cols = size.max(30);
+    let n_rows = (n_cols / 4).max(5);
+    let mut map: Vec<Vec<u8>> = (0..n_rows)
+        .map(|_| (0..n_cols).map(|_| rng.range(b'a'..=b'z')).collect())
+        .collect();
+    // The trail mostly goes right, and it never goes back up (or down) without moving right,
+    // so it never crosses itself. It is longer than the number of heights, so it can climb
+    // one level at a time.
+    let mut row = rng.index(n_rows);
+    let mut trail = vec![(row, 0)];
+    let (mut col, mut vertical) = (0, 0_i32);
+    while col < n_cols - 1 {
+        let step = match rng.range(0..=3) {
+            0 if row > 0 && vertical <= 0 => -1,
+            1 if row < n_rows - 1 && vertical >= 0 => 1,
+            _ => 0,
+        };
+        match step {
+            0 => {
+                col += 1;
+                vertical = 0;
+            }
+            _ => {
+                row = (row as i32 + step) as usize;
+                vertical = step;
+            }
+        }
+        trail.push((row, col));
+    }
+    for (k, &(i, j)) in trail.iter().enumerate() {
+        map[i][j] = b'a' + (25 * k / (trail.len() - 1)) as u8;
+    }
+    let (start, end) = (trail[0], trail[trail.len() - 1]);
+    map[start.0][start.1] = b'S';
+    map[end.0][end.1] = b'E';
+    join_lines(map.into_iter().map(|row| String::from_utf8(row).unwrap()))
+}
+
+/// It writes a random packet: a list of numbers and lists.
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.range(0..=5))
+        .map(|_| match depth < 4 && rng.chance(0.3) {
+            true => packet(rng, depth + 1),
+            false => rng.range(0..=10).to_string(),
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Pairs of packets.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let pairs = (0..size.max(1)).map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)));
+    pairs.collect::<Vec<_>>().join("\n")
+}
+
+/// Paths of rock, made of horizontal and vertical segments below the source of sand.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    let depth = 10 + size as i32;
+    join_lines((0..size.max(1)).map(|_| {
+        let (mut x, mut y) = (rng.range(440..=560), rng.range(5..=depth));
+        let mut points = vec![format!("{},{}", x, y)];
+        for k in 0..rng.range(1..=5) {
+            let delta = rng.range(1..=8) * if rng.chance(0.5) { 1 } else { -1 };
+            match k % 2 {
+                0 => x += delta,
+                _ => y = (y + delta).max(1),
+            }
+            points.push(format!("{},{}", x, y));
+        }
+        points.join(" -> ")
+    }))
+}
+
+/// Sensors and beacons spread over the search space of the real puzzle (4000000 positions).
+/// A grid of sensors covers the whole space, and four sensors surround the only gap.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    const MAX_VAL: i64 = 4_000_000;
+    let side = ((size as f64).sqrt() as i64).max(2);
+    let step = MAX_VAL / side;
+    let max_radius = step + step / 4;
+    let dist = |a: (i64, i64), b: (i64, i64)| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+    let gap = (rng.range(1..=MAX_VAL - 1), rng.range(1..=MAX_VAL - 1));
+    // A sensor at any point of the grid covers its corner of every cell that touches it.
+    // We remove the sensors that see the gap, and then the four sensors around the gap cover
+    // the hole that they leave (the box around the gap, except the gap itself).
+    let mut sensors = Vec::new();
+    for i in 0..=side {
+        for j in 0..=side {
+            let sensor = (i * step, j * step);
+            let radius = rng.range(step..=max_radius);
+            if dist(sensor, gap) > radius {
+                sensors.push((sensor, radius));
+            }
+        }
+    }
+    let d = 2 * max_radius;
+    for (dx, dy) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+        sensors.push(((gap.0 + dx * d, gap.1 + dy * d), 2 * d - 1));
+    }
+    rng.shuffle(&mut sensors);
+    join_lines(sensors.into_iter().map(|((x, y), radius)| {
+        // The beacon is anywhere in the border of the area of the sensor
+        let along = rng.range(0..=radius);
+        let (sx, sy) = (*rng.pick(&[-1, 1]), *rng.pick(&[-1, 1]));
+        let beacon = (x + sx * along, y + sy * (radius - along));
+        format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            x, y, beacon.0, beacon.1
+        )
+    }))
+}
+
+/// Connected network of valves. Only a few of them have flow, as in the real puzzle.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let n_valves = size.clamp(2, 64);
+    let mut names = BTreeSet::from(["AA".to_string()]);
+    while names.len() < n_valves {
+        names.insert((0..2).map(|_| rng.range(b'A'..=b'Z') as char).collect());
+    }
+    let mut names: Vec<String> = names.into_iter().collect();
+    rng.shuffle(&mut names);
+    let start = names.iter().position(|n| n == "AA").unwrap();
+    names.swap(0, start);
+    // A random tree connects every valve, and a few more tunnels create loops
+    let mut tunnels = vec![BTreeSet::new(); n_valves];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for i in 1..n_valves {
+        connect(i, rng.index(i));
+    }
+    for _ in 0..n_valves / 3 {
+        connect(rng.index(n_valves), rng.index(n_valves));
+    }
+    let mut flows = vec![0; n_valves];
+    let n_flows = (n_valves / 4).clamp(1, 15);
+    for _ in 0..n_flows {
+        flows[rng.range(1..=n_valves - 1)] = rng.range(1..=25);
+    }
+    let mut lines: Vec<String> = (0..n_valves)
+        .map(|i| {
+            let others: Vec<&str> = tunnels[i].iter().map(|&j| names[j].as_str()).collect();
+            let plural = match others.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}",
+                names[i],
+                flows[i],
+                plural,
+                others.join(", ")
+            )
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    join_lines(lines)
+}
+
+/// Pattern of jets.
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let jets: String = (0..size.max(1)).map(|_| *rng.pick(&['<', '>'])).collect();
+    jets + "\n"
+}
+
+/// Cubes of a lava droplet. It is a random walk, so the droplet has pockets of air.
+fn day18(rng: &mut Rng, size: usize) -> String {
+    const SIDE: i32 = 22;
+    let size = size.clamp(1, (SIDE * SIDE * SIDE) as usize);
+    let mut cube = [SIDE / 2; 3];
+    let mut cubes = vec![cube];
+    let mut seen = HashSet::from([cube]);
+    while cubes.len() < size {
+        let axis = rng.index(3);
+        cube[axis] = (cube[axis] + *rng.pick(&[-1, 1])).clamp(0, SIDE - 1);
+        if seen.insert(cube) {
+            cubes.push(cube);
+        }
+    }
+    rng.shuffle(&mut cubes);
+    join_lines(
+        cubes
+            .into_iter()
+            .map(|[x, y, z]| format!("{},{},{}", x, y, z)),
+    )
+}
+
+/// Blueprints with costs similar to those of the real puzzle.
+fn day19(rng: &mut Rng, size: usize) -> String {
+    join_lines((1..=size.max(1)).map(|i| {
+        format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            i,
+            rng.range(2..=4),
+            rng.range(2..=4),
+            rng.range(2..=4),
+            rng.range(4..=20),
+            rng.range(2..=4),
+            rng.range(4..=20),
+        )
+    }))
+}
+
+/// Encrypted file. It contains exactly one zero.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    // The file needs at least two numbers (the 0 and another one)
+    let mut numbers: Vec<i32> = (1..size.max(2))
+        .map(|_| rng.range(1..=10000) * *rng.pick(&[-1, 1]))
+        .collect();
+    numbers.insert(rng.index(numbers.len() + 1), 0);
+    join_lines(numbers.into_iter().map(|n| n.to_string()))
+}
+
+/// Monkeys of the riddle of day 21. Divisions are always exact.
+struct Riddle {
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Riddle {
+    /// It returns a new name for a monkey.
+    fn name(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name: String = (0..4).map(|_| rng.range(b'a'..=b'z') as char).collect();
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// It adds `size` monkeys whose riddle yields `value` (which must be positive).
+    /// It returns the name of the monkey at the top.
+    fn constant(&mut self, rng: &mut Rng, value: i64, size: usize) -> String {
+        let name = self.name(rng);
+        if size < 3 {
+            self.lines.push(format!("{}: {}", name, value));
+            return name;
+        }
+        let divisors: Vec<i64> = (2..=9).filter(|d| value % d == 0).collect();
+        let (lhs, op, rhs) = match rng.range(0..=3) {
+            0 if value >= 2 => {
+                let lhs = rng.range(1..=value - 1);
+                (lhs, '+', value - lhs)
+            }
+            1 if !divisors.is_empty() => {
+                let rhs = *rng.pick(&divisors);
+                (value / rhs, '*', rhs)
+            }
+            2 if value < 1000 => {
+                let rhs = rng.range(2..=5);
+                (value * rhs, '/', rhs)
+            }
+            _ => {
+                let rhs = rng.range(1..=10);
+                (value + rhs, '-', rhs)
+            }
+        };
+        let left = rng.range(1..=size - 2);
+        let lhs = self.constant(rng, lhs, left);
+        let rhs = self.constant(rng, rhs, size - 1 - left);
+        self.lines.push(format!("{}: {} {} {}", name, lhs, op, rhs));
+        name
+    }
+}
+
+/// Monkeys of the riddle. I appear only once, so the equation of the second part is linear,
+/// and its solution is an integer. My number in the first part is different.
+fn day21(rng: &mut Rng, size: usize) -> String {
+    let mut riddle = Riddle {
+        names: HashSet::from(["root".to_string(), "humn".to_string()]),
+        lines: Vec::new(),
+    };
+    let size = size.max(5);
+    let n_steps = (size / 8).clamp(1, 50);
+    let budget = (size - n_steps - 2) / (n_steps + 1);
+    // We climb from my number to the root, tracking both values of my number
+    let solution = rng.range(1..=1000_i64);
+    let (mut x, mut x1) = (solution, solution + 3600 * rng.range(1..=9));
+    riddle.lines.push(format!("humn: {}", x1));
+    let mut current = "humn".to_string();
+    for _ in 0..n_steps {
+        let name = riddle.name(rng);
+        let divisors: Vec<i64> = (2..=5).filter(|d| x % d == 0 && x1 % d == 0).collect();
+        let small = x.abs().max(x1.abs()) < 1_000_000_000_000;
+        let line = match rng.range(0..=3) {
+            0 if small => {
+                let c = rng.range(2..=5);
+                (x, x1) = (x * c, x1 * c);
+                let c = riddle.constant(rng, c, budget);
+                match rng.chance(0.5) {
+                    true => format!("{}: {} * {}", name, current, c),
+                    false => format!("{}: {} * {}", name, c, current),
+                }
+            }
+            1 if !divisors.is_empty() => {
+                let c = *rng.pick(&divisors);
+                (x, x1) = (x / c, x1 / c);
+                format!(
+                    "{}: {} / {}",
+                    name,
+                    current,
+                    riddle.constant(rng, c, budget)
+                )
+            }
+            2 => {
+                let c = rng.range(1..=1000);
+                (x, x1) = (c - x, c - x1);
+                format!(
+                    "{}: {} - {}",
+                    name,
+                    riddle.constant(rng, c, budget),
+                    current
+                )
+            }
+            _ => {
+                let c = rng.range(1..=1000);
+                (x, x1) = (x + c, x1 + c);
+                let c = riddle.constant(rng, c, budget);
+                match rng.chance(0.5) {
+                    true => format!("{}: {} + {}", name, current, c),
+                    false => format!("{}: {} + {}", name, c, current),
+                }
+            }
+        };
+        riddle.lines.push(line);
+        current = name;
+    }
+    // The other side of the root yields my side when I yell the solution
+    let other = match x > 0 {
+        true => riddle.constant(rng, x, budget),
+        false => {
+            let c = riddle.constant(rng, 1 - x, budget);
+            let one = riddle.constant(rng, 1, 1);
+            let name = riddle.name(rng);
+            riddle.lines.push(format!("{}: {} - {}", name, one, c));
+            name
+        }
+    };
+    riddle.lines.push(match rng.chance(0.5) {
+        true => format!("root: {} + {}", current, other),
+        false => format!("root: {} + {}", other, current),
+    });
+    let mut lines = riddle.lines;
+    rng.shuffle(&mut lines);
+    join_lines(lines)
+}
+
+/// The eleven nets of a cube (in faces).
+const NETS: [&[&str]; 11] = [
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+/// Map of the monkeys and path. The map is a random net of a cube, rotated and flipped.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(2) as i32;
+    let net = rng.pick(&NETS);
+    let mut faces: Vec<(i32, i32)> = net
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (x as i32, y as i32))
+        })
+        .collect();
+    for _ in 0..rng.range(0..=3) {
+        faces = faces.into_iter().map(|(x, y)| (y, -x)).collect();
+    }
+    if rng.chance(0.5) {
+        faces = faces.into_iter().map(|(x, y)| (-x, y)).collect();
+    }
+    let min_x = faces.iter().map(|f| f.0).min().unwrap();
+    let min_y = faces.iter().map(|f| f.1).min().unwrap();
+    let faces: HashSet<(i32, i32)> = faces
+        .into_iter()
+        .map(|(x, y)| (x - min_x, y - min_y))
+        .collect();
+    let n_rows = faces.iter().map(|f| f.1).max().unwrap() + 1;
+    let n_cols = faces.iter().map(|f| f.0).max().unwrap() + 1;
+    let mut rows = Vec::new();
+    for y in 0..n_rows * side {
+        let mut row: String = (0..n_cols * side)
+            .map(|x| match faces.contains(&(x / side, y / side)) {
+                false => ' ',
+                true => match rng.chance(0.1) {
+                    true => '#',
+                    false => '.',
+                },
+            })
+            .collect();
+        // We always start in an open tile
+        if y == 0 {
+            let start = row.find('#').filter(|&i| !row[..i].contains('.'));
+            if let Some(i) = start {
+                row.replace_range(i..i + 1, ".");
+            }
+        }
+        rows.push(row.trim_end().to_string());
+    }
+    let mut path = rng.range(1..=side).to_string();
+    for _ in 0..20 * side {
+        path.push(*rng.pick(&['L', 'R']));
+        path.push_str(&rng.range(1..=side).to_string());
+    }
+    format!("{}\n{}\n", join_lines(rows), path)
+}
+
+/// Elves in a square grove.
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    grid(size, size, || match rng.chance(0.5) {
+        true => '#',
+        false => '.',
+    })
+}
+
+/// It returns the minute when the expedition reaches the other side of a valley (without its
+/// walls) if it leaves at minute `start` from the entrance (or from the exit if not `forward`).
+/// It is a BFS over the positions and the minutes of a lap of the blizzards.
+fn cross(valley: &[Vec<char>], start: usize, forward: bool) -> Option<usize> {
+    let (n_rows, n_cols) = (valley.len(), valley[0].len());
+    let lap = lcm(n_rows, n_cols);
+    let free = |(i, j): (usize, usize), t: usize| {
+        valley[i][(j + n_cols - t % n_cols) % n_cols] != '>'
+            && valley[i][(j + t) % n_cols] != '<'
+            && valley[(i + n_rows - t % n_rows) % n_rows][j] != 'v'
+            && valley[(i + t) % n_rows][j] != '^'
+    };
+    let (first, last) = match forward {
+        true => ((0, 0), (n_rows - 1, n_cols - 1)),
+        false => ((n_rows - 1, n_cols - 1), (0, 0)),
+    };
+    // `None` is the side where we start, where we can always wait
+    let mut seen = HashSet::from([(None, start % lap)]);
+    let mut pending = VecDeque::from([(None, start)]);
+    while let Some((position, t)) = pending.pop_front() {
+        let next: Vec<Option<(usize, usize)>> = match position {
+            None => vec![None, Some(first)],
+            Some(p) if p == last => return Some(t + 1),
+            Some((i, j)) => {
+                let mut next = vec![Some((i, j)), Some((i + 1, j)), Some((i, j + 1))];
+                next.extend([
+                    i.checked_sub(1).map(|i| (i, j)),
+                    j.checked_sub(1).map(|j| (i, j)),
+                ]);
+                if (i, j) == first {
+                    next.push(None);
+                }
+                next
+            }
+        };
+        for position in next {
+            let ok = match position {
+                None => true,
+                Some((i, j)) => i < n_rows && j < n_cols && free((i, j), t + 1),
+            };
+            if ok && seen.insert((position, (t + 1) % lap)) {
+                pending.push_back((position, t + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Valley with blizzards. Blizzards never go up or down in the columns of the entrance and the
+/// exit (they would leave the valley), and the expedition can always cross the valley,
+/// go back for the snacks, and cross it again (we try until we find such a valley).
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let n_cols = size.max(3);
+    let n_rows = (n_cols / 5).max(2);
+    let valley = loop {
+        let valley: Vec<Vec<char>> = (0..n_rows)
+            .map(|_| {
+                (0..n_cols)
+                    .map(|j| {
+                        let arrows: &[char] = match j == 0 || j == n_cols - 1 {
+                            true => &['<', '>'],
+                            false => &['<', '>', '^', 'v'],
+                        };
+                        match rng.chance(0.5) {
+                            true => *rng.pick(arrows),
+                            false => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let there = cross(&valley, 0, true);
+        let back = there.and_then(|t| cross(&valley, t, false));
+        if back.and_then(|t| cross(&valley, t, true)).is_some() {
+            break valley;
+        }
+    };
+    let mut res = format!("#.{}\n", "#".repeat(n_cols));
+    for row in valley {
+        writeln!(res, "#{}#", row.into_iter().collect::<String>()).unwrap();
+    }
+    writeln!(res, "{}.#", "#".repeat(n_cols)).unwrap();
+    res
+}
+
+/// Fuel requirements in SNAFU.
+fn day25(rng: &mut Rng, size: usize) -> String {
+    join_lines((0..size.max(1)).map(|_| {
+        let digits = rng.range(1..=20);
+        dec_to_snafu(rng.range(1..=5_i64.pow(digits) / 2))
+    }))
+}
@@ -0,0 +1,391 @@
+//! 2D geometry shared by the puzzles that move things around a map.
+//!
+//! Unless stated otherwise, we use screen coordinates: `x` grows to the right and `y` grows
+//! downwards, as rows of the input files do. Thus, turning right is a clockwise rotation.
+
+use num::Signed;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A point (or a vector) in a 2D space. Coordinates are `i32` by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i32> {
+    /// x coordinate (column).
+    pub x: T,
+    /// y coordinate (row).
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    /// It creates a new point.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Neg<Output = T>> Point<T> {
+    /// It rotates the vector 90 degrees to the right (clockwise).
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// It rotates the vector 90 degrees to the left (counterclockwise).
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl<T: Signed + Copy + PartialOrd> Point<T> {
+    /// It returns a vector with the absolute value of every coordinate.
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+
+    /// It returns a vector with the sign (-1, 0, or 1) of every coordinate.
+    /// It is handy for moving one step at a time towards another point.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// It returns the Manhattan (taxicab) distance between two points.
+    pub fn manhattan(self, other: Self) -> T {
+        let d = (self - other).abs();
+        d.x + d.y
+    }
+
+    /// It returns the Chebyshev (chessboard) distance between two points.
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = (self - other).abs();
+        match d.x > d.y {
+            true => d.x,
+            false => d.y,
+        }
+    }
+
+    /// It returns the 4 orthogonal neighbours of the point (clockwise, starting from above).
+    pub fn neighbours4(self) -> [Self; 4] {
+        let (o, z) = (T::one(), T::zero());
+        [(z, -o), (o, z), (z, o), (-o, z)].map(|(x, y)| self + Self::new(x, y))
+    }
+
+    /// It returns the 8 neighbours of the point, diagonals included
+    /// (clockwise, starting from above).
+    pub fn neighbours8(self) -> [Self; 8] {
+        let (o, z) = (T::one(), T::zero());
+        [
+            (z, -o),
+            (o, -o),
+            (o, z),
+            (o, o),
+            (z, o),
+            (-o, o),
+            (-o, z),
+            (-o, -o),
+        ]
+        .map(|(x, y)| self + Self::new(x, y))
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// Scaling a vector.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: MulAssign + Copy> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+/// Scaling a vector down. Integer coordinates are truncated.
+impl<T: Div<Output = T> + Copy> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl<T: DivAssign + Copy> DivAssign<T> for Point<T> {
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Orthogonal directions of a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// Turns that change a [`Direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// All the directions, clockwise starting from up (i.e., in the same order as the variants).
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// It returns the unit vector of the direction.
+    pub fn vector(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// It returns the direction of a unit vector (if any).
+    pub fn from_vector(vector: Point) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.vector() == vector)
+    }
+
+    /// It returns the direction of a letter (`U`, `R`, `D`, or `L`).
+    pub fn from_letter(c: char) -> Option<Self> {
+        match c {
+            'U' => Some(Self::Up),
+            'R' => Some(Self::Right),
+            'D' => Some(Self::Down),
+            'L' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// It returns the direction of an arrow (`^`, `>`, `v`, or `<`).
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Self::Up),
+            '>' => Some(Self::Right),
+            'v' => Some(Self::Down),
+            '<' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// It returns the arrow of the direction (`^`, `>`, `v`, or `<`).
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+
+    /// It returns the direction after turning 90 degrees to the right.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// It returns the direction after turning 90 degrees to the left.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    /// It returns the direction after a turn.
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    /// It returns the opposite direction.
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+/// Directions of a map, diagonals included. North is up (i.e., towards negative `y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// All the directions, clockwise starting from north (i.e., in the same order as the variants
+    /// and as [`Point::neighbours8`]).
+    pub const ALL: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    /// It returns the vector of the direction (diagonals have a 1 in both coordinates).
+    pub fn vector(self) -> Point {
+        match self {
+            Self::N => Point::new(0, -1),
+            Self::NE => Point::new(1, -1),
+            Self::E => Point::new(1, 0),
+            Self::SE => Point::new(1, 1),
+            Self::S => Point::new(0, 1),
+            Self::SW => Point::new(-1, 1),
+            Self::W => Point::new(-1, 0),
+            Self::NW => Point::new(-1, -1),
+        }
+    }
+
+    /// It returns the direction of a vector (if any).
+    pub fn from_vector(vector: Point) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.vector() == vector)
+    }
+
+    /// It returns true if the direction is a diagonal.
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// It returns the direction after turning 45 degrees to the right.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// It returns the direction after turning 45 degrees to the left.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// It returns the opposite direction.
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// It returns the direction and the two directions next to it, from left to right
+    /// (e.g., `NW`, `N`, and `NE` for `N`).
+    pub fn fan(self) -> [Self; 3] {
+        [self.turn_left(), self, self.turn_right()]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::N,
+            Direction::Right => Self::E,
+            Direction::Down => Self::S,
+            Direction::Left => Self::W,
+        }
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(direction: Direction8) -> Self {
+        direction.vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions8() {
+        let origin = Point::new(3, -2);
+        assert_eq!(
+            Direction8::ALL.map(|d| origin + d.vector()),
+            origin.neighbours8()
+        );
+        for d in Direction8::ALL {
+            assert_eq!(Direction8::from_vector(d.vector()), Some(d));
+            assert_eq!(d.opposite().vector(), -d.vector());
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.is_diagonal(), d.vector().x != 0 && d.vector().y != 0);
+        }
+        assert_eq!(
+            Direction8::N.fan(),
+            [Direction8::NW, Direction8::N, Direction8::NE]
+        );
+        assert_eq!(
+            Direction8::W.fan(),
+            [Direction8::SW, Direction8::W, Direction8::NW]
+        );
+        for d in Direction::ALL {
+            assert_eq!(Direction8::from(d).vector(), d.vector());
+        }
+    }
+}
@@ -0,0 +1,457 @@
+//! Dense 2D grids, the bread and butter of the puzzles with maps.
+//!
+//! Cells are indexed with [`Point`]s (`x` is the column, `y` is the row), so we can use the same
+//! vectors and directions as in [`crate::geom`]. Points out of the grid are not an error:
+//! [`Grid::get`] returns `None`, and [`Grid::get_wrapping`] wraps them around the edges.
+
+use crate::geom::{Direction, Point};
+use crate::AocError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    n_rows: usize,
+    n_cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// It creates a grid with all its cells set to `value`.
+    pub fn new(n_rows: usize, n_cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            n_rows,
+            n_cols,
+            cells: vec![value; n_rows * n_cols],
+        }
+    }
+
+    /// It creates a grid from its rows. If the rows do not have the same length, it returns `None`.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != n_cols) {
+            return None;
+        }
+        Some(Self {
+            n_rows,
+            n_cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// It parses a map with one character per cell. `cell` converts characters to cells,
+    /// and returns `None` if a character is not valid.
+    /// The map must be a non-empty rectangle.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, AocError> {
+        let (mut n_rows, mut n_cols, mut cells) = (0, 0, Vec::new());
+        for (i, line) in input.lines().enumerate() {
+            let mut n = 0;
+            for (j, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(AocError::unexpected_char(i + 1, j + 1, c))?);
+                n += 1;
+            }
+            if i == 0 {
+                n_cols = n;
+            } else if n != n_cols {
+                return Err(AocError::parse(
+                    i + 1,
+                    1,
+                    format!("expected {} cells, found {}", n_cols, n),
+                ));
+            }
+            n_rows += 1;
+        }
+        match cells.is_empty() {
+            true => Err(AocError::unexpected_end(1, "a row of cells")),
+            false => Ok(Self {
+                n_rows,
+                n_cols,
+                cells,
+            }),
+        }
+    }
+
+    /// Number of rows of the grid.
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    /// Number of columns of the grid.
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// It returns true if the point lies within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    /// Position of a point in the vector of cells (if the point lies within the grid).
+    fn offset(&self, point: Point) -> Option<usize> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        match x < self.n_cols && y < self.n_rows {
+            true => Some(y * self.n_cols + x),
+            false => None,
+        }
+    }
+
+    /// Point of a given position of the vector of cells.
+    fn point(&self, offset: usize) -> Point {
+        Point::new((offset % self.n_cols) as i32, (offset / self.n_cols) as i32)
+    }
+
+    /// It returns the cell in a given point (if any).
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    /// It returns a mutable reference to the cell in a given point (if any).
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// It wraps a point around the edges of the grid, as if it were a torus.
+    /// The grid must not be empty.
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            point.x.rem_euclid(self.n_cols as i32),
+            point.y.rem_euclid(self.n_rows as i32),
+        )
+    }
+
+    /// It returns the cell in a given point after wrapping it around the edges of the grid.
+    /// The grid must not be empty.
+    pub fn get_wrapping(&self, point: Point) -> &T {
+        &self[self.wrap(point)]
+    }
+
+    /// It returns an iterator over all the cells and their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (self.point(i), c))
+    }
+
+    /// It returns an iterator over the points whose cells satisfy a predicate.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, c)| predicate(c))
+            .map(|(p, _)| p)
+    }
+
+    /// It returns an iterator over the rows of the grid.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks panics with a zero length, but then there are no cells anyway
+        self.cells.chunks(self.n_cols.max(1))
+    }
+
+    /// It returns a row of the grid (if any).
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.cells.get(y * self.n_cols..(y + 1) * self.n_cols)
+    }
+
+    /// It returns an iterator over the cells of a column, from top to bottom.
+    /// If the column does not exist, the iterator is empty.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let skip = match x < self.n_cols {
+            true => x,
+            false => self.cells.len(),
+        };
+        self.cells.iter().skip(skip).step_by(self.n_cols.max(1))
+    }
+
+    /// It returns the orthogonal neighbours of a point that lie within the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours4()
+            .into_iter()
+            .filter(|&p| self.contains(p))
+    }
+
+    /// It returns the neighbours of a point (diagonals included) that lie within the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbours8()
+            .into_iter()
+            .filter(|&p| self.contains(p))
+    }
+
+    /// It returns the points that we find if we walk from `point` towards `direction`
+    /// until we leave the grid. The starting point is not included.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        let step = direction.vector();
+        std::iter::successors(Some(point + step), move |&p| Some(p + step))
+            .take_while(|&p| self.contains(p))
+    }
+
+    /// It creates a new grid applying a function to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// It adds a row at the bottom of the grid with all its cells set to `value`.
+    pub fn push_row(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.extend(std::iter::repeat_n(value, self.n_cols));
+        self.n_rows += 1;
+    }
+
+    /// It adds rows and columns with all their cells set to `value` around the grid.
+    /// Cells that were in `(x, y)` are now in `(x + left, y + top)`.
+    pub fn pad(&mut self, top: usize, right: usize, bottom: usize, left: usize, value: T)
+    where
+        T: Clone,
+    {
+        let n_cols = left + self.n_cols + right;
+        let mut cells = Vec::with_capacity((top + self.n_rows + bottom) * n_cols);
+        cells.extend(std::iter::repeat_n(value.clone(), top * n_cols));
+        for row in self.rows() {
+            cells.extend(std::iter::repeat_n(value.clone(), left));
+            cells.extend_from_slice(row);
+            cells.extend(std::iter::repeat_n(value.clone(), right));
+        }
+        cells.extend(std::iter::repeat_n(value, bottom * n_cols));
+        *self = Self {
+            n_rows: top + self.n_rows + bottom,
+            n_cols,
+            cells,
+        };
+    }
+
+    /// It returns a view of a rectangle of the grid with its upper-left corner in `origin`.
+    /// If the rectangle does not fit in the grid, it returns `None`.
+    pub fn view(&self, origin: Point, n_rows: usize, n_cols: usize) -> Option<View<'_, T>> {
+        let fits = |start: i32, len: usize, limit: usize| {
+            usize::try_from(start).is_ok_and(|s| s + len <= limit)
+        };
+        match fits(origin.x, n_cols, self.n_cols) && fits(origin.y, n_rows, self.n_rows) {
+            true => Some(View {
+                grid: self,
+                origin,
+                n_rows,
+                n_cols,
+            }),
+            false => None,
+        }
+    }
+
+    /// It returns a view of the whole grid.
+    pub fn as_view(&self) -> View<'_, T> {
+        View {
+            grid: self,
+            origin: Point::default(),
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+        }
+    }
+
+    /// It draws the grid with one symbol per cell (see [`View::render`]).
+    pub fn render<R: Display>(&self, symbol: impl FnMut(&T) -> R) -> String {
+        self.as_view().render(symbol)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// It panics if the point lies out of the grid. Use [`Grid::get`] if you are not sure.
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("point {} is out of the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    /// It panics if the point lies out of the grid. Use [`Grid::get_mut`] if you are not sure.
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match self.offset(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("point {} is out of the grid", point),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_view().fmt(f)
+    }
+}
+
+/// A rectangle inside a [`Grid`]. Points are relative to the upper-left corner of the view.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    n_rows: usize,
+    n_cols: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    /// Number of rows of the view.
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    /// Number of columns of the view.
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// It returns the cell in a given point of the view (if any).
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        let inside = usize::try_from(point.x).is_ok_and(|x| x < self.n_cols)
+            && usize::try_from(point.y).is_ok_and(|y| y < self.n_rows);
+        match inside {
+            true => self.grid.get(self.origin + point),
+            false => None,
+        }
+    }
+
+    /// It returns an iterator over the rows of the view.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + '_ {
+        let (x, y) = (self.origin.x as usize, self.origin.y as usize);
+        self.grid
+            .rows()
+            .skip(y)
+            .take(self.n_rows)
+            .map(move |row| &row[x..x + self.n_cols])
+    }
+
+    /// It draws the view with one symbol per cell and one line per row.
+    /// `symbol` decides how every cell looks like, so the same grid can be drawn in many ways.
+    pub fn render<R: Display>(&self, mut symbol: impl FnMut(&T) -> R) -> String {
+        let mut res = String::new();
+        for row in self.rows() {
+            for cell in row {
+                res += &symbol(cell).to_string();
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3 rows and 4 columns, with the cell in `(x, y)` set to `10 * y + x`.
+    fn grid() -> Grid<i32> {
+        Grid::from_rows(
+            (0..3)
+                .map(|y| (0..4).map(|x| 10 * y + x).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn get_out_of_bounds() {
+        let grid = grid();
+        assert_eq!(grid.get(Point::new(3, 2)), Some(&23));
+        for point in [(4, 0), (0, 3), (-1, 0), (0, -1), (i32::MIN, i32::MAX)] {
+            assert_eq!(grid.get(Point::new(point.0, point.1)), None, "{:?}", point);
+        }
+        assert_eq!(grid.row(2), Some(&[20, 21, 22, 23][..]));
+        assert_eq!(grid.row(3), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = grid();
+        assert_eq!(grid.wrap(Point::new(4, 3)), Point::new(0, 0));
+        assert_eq!(grid.wrap(Point::new(-1, -1)), Point::new(3, 2));
+        assert_eq!(grid.wrap(Point::new(-9, 7)), Point::new(3, 1));
+        assert_eq!(*grid.get_wrapping(Point::new(-5, -4)), 23);
+        assert_eq!(*grid.get_wrapping(Point::new(1, 2)), 21);
+    }
+
+    #[test]
+    fn column_bounds() {
+        let grid = grid();
+        assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), [0, 10, 20]);
+        assert_eq!(grid.column(3).copied().collect::<Vec<_>>(), [3, 13, 23]);
+        assert_eq!(grid.column(4).count(), 0);
+        assert_eq!(grid.column(usize::MAX).count(), 0);
+    }
+
+    #[test]
+    fn view_bounds() {
+        let grid = grid();
+        let view = grid.view(Point::new(1, 1), 2, 3).unwrap();
+        assert_eq!(view.to_string(), "111213\n212223\n");
+        assert_eq!(view.get(Point::new(0, 0)), Some(&11));
+        // Points of the view are relative to its corner, and it hides the rest of the grid
+        assert_eq!(view.get(Point::new(-1, 0)), None);
+        assert_eq!(view.get(Point::new(3, 0)), None);
+        assert_eq!(view.get(Point::new(0, 2)), None);
+        assert!(grid.view(Point::new(0, 0), 3, 4).is_some());
+        assert!(grid.view(Point::new(4, 3), 0, 0).is_some());
+        assert!(grid.view(Point::new(1, 1), 3, 3).is_none());
+        assert!(grid.view(Point::new(1, 1), 2, 4).is_none());
+        assert!(grid.view(Point::new(-1, 0), 1, 1).is_none());
+    }
+
+    #[test]
+    fn pad() {
+        let mut grid = grid();
+        grid.pad(1, 2, 0, 1, -1);
+        assert_eq!((grid.n_rows(), grid.n_cols()), (4, 7));
+        assert_eq!(grid.row(0), Some(&[-1; 7][..]));
+        assert_eq!(grid.row(1), Some(&[-1, 0, 1, 2, 3, -1, -1][..]));
+        assert_eq!(grid[Point::new(4, 3)], 23);
+        grid.pad(0, 0, 0, 0, -1);
+        assert_eq!((grid.n_rows(), grid.n_cols()), (4, 7));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let grid = Grid::parse("#.\n.#\n", |c| (c == '#').then_some(1).or(Some(0))).unwrap();
+        assert_eq!(
+            grid.positions(|&c| c == 1).collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(1, 1)]
+        );
+        let digits = |c: char| c.to_digit(10);
+        assert_eq!(
+            Grid::parse("12\n3x\n", digits).unwrap_err(),
+            AocError::unexpected_char(2, 2, 'x')
+        );
+        assert!(matches!(
+            Grid::parse("12\n345\n", digits).unwrap_err(),
+            AocError::Parse { line: 2, .. }
+        ));
+        assert!(Grid::parse("", digits).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! My answers for the 2022 Advent of Code.
+//!
+//! Every puzzle lives in its own module inside [`days`] and implements the [`Solution`] trait.
+//! The [`solve`] function dispatches to the solver of any day and part,
+//! and [`parse`] gives access to the parsed input of any day.
+
+use std::fmt::Display;
+
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod diag;
+mod error;
+pub mod export;
+pub mod generate;
+pub mod geom;
+pub mod grid;
+pub mod par;
+pub mod parser;
+pub mod report;
+pub mod search;
+pub mod sparse;
+pub mod store;
+pub mod viz;
+
+pub use error::AocError;
+
+/// Number of puzzles in the advent calendar.
+pub const N_DAYS: usize = 25;
+
+/// Every puzzle has two parts.
+pub const N_PARTS: usize = 2;
+
+/// Common interface of the solutions of every day.
+pub trait Solution: Sized {
+    /// Answer to the first part of the puzzle.
+    type Answer1: Display;
+    /// Answer to the second part of the puzzle.
+    type Answer2: Display;
+
+    /// It parses the puzzle input.
+    fn parse(input: &str) -> Result<Self, AocError>;
+
+    /// It solves the first part of the puzzle.
+    fn part1(&self) -> Result<Self::Answer1, AocError>;
+
+    /// It solves the second part of the puzzle.
+    fn part2(&self) -> Result<Self::Answer2, AocError>;
+}
+
+/// Object-safe view of a parsed [`Solution`], so we can handle the solutions of every day alike.
+pub trait Parsed {
+    /// It solves the first part of the puzzle and returns the answer as a string.
+    fn answer1(&self) -> Result<String, AocError>;
+
+    /// It solves the second part of the puzzle and returns the answer as a string.
+    fn answer2(&self) -> Result<String, AocError>;
+}
+
+impl<S: Solution> Parsed for S {
+    fn answer1(&self) -> Result<String, AocError> {
+        Ok(self.part1()?.to_string())
+    }
+
+    fn answer2(&self) -> Result<String, AocError> {
+        Ok(self.part2()?.to_string())
+    }
+}
+
+/// It parses the `input` of a solution and hides its type.
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, AocError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// Signature of the functions that parse the raw input of a puzzle.
+type Parser = fn(&str) -> Result<Box<dyn Parsed>, AocError>;
+
+/// Parsers of every puzzle, sorted by day.
+const PARSERS: [Parser; N_DAYS] = [
+    parse_boxed::<days::Day01>,
+    parse_boxed::<days::Day02>,
+    parse_boxed::<days::Day03>,
+    parse_boxed::<days::Day04>,
+    parse_boxed::<days::Day05>,
+    parse_boxed::<days::Day06>,
+    parse_boxed::<days::Day07>,
+    parse_boxed::<days::Day08>,
+    parse_boxed::<days::Day09>,
+    parse_boxed::<days::Day10>,
+    parse_boxed::<days::Day11>,
+    parse_boxed::<days::Day12>,
+    parse_boxed::<days::Day13>,
+    parse_boxed::<days::Day14>,
+    parse_boxed::<days::Day15>,
+    parse_boxed::<days::Day16>,
+    parse_boxed::<days::Day17>,
+    parse_boxed::<days::Day18>,
+    parse_boxed::<days::Day19>,
+    parse_boxed::<days::Day20>,
+    parse_boxed::<days::Day21>,
+    parse_boxed::<days::Day22>,
+    parse_boxed::<days::Day23>,
+    parse_boxed::<days::Day24>,
+    parse_boxed::<days::Day25>,
+];
+
+/// It parses the `input` of the puzzle of a given `day` (1 to 25).
+pub fn parse(day: usize, input: &str) -> Result<Box<dyn Parsed>, AocError> {
+    let parser = day
+        .checked_sub(1)
+        .and_then(|i| PARSERS.get(i))
+        .ok_or(AocError::UnknownPuzzle { day, part: 1 })?;
+    parser(input)
+}
+
+/// It solves the given `part` (1 or 2) of the puzzle of a given `day` (1 to 25) from its `input`.
+pub fn solve(day: usize, part: usize, input: &str) -> Result<String, AocError> {
+    match part {
+        1 => parse(day, input)?.answer1(),
+        2 => parse(day, input)?.answer2(),
+        _ => Err(AocError::UnknownPuzzle { day, part }),
+    }
+}
+
+/// It returns the default location of the input file of a given day.
+pub fn input_path(day: usize) -> String {
+    format!("data/{:02}_input.txt", day)
+}
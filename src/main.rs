@@ -1,71 +1,801 @@
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
-
-/// Reads the input file and returns the list of numbers.
-fn read_input(path: &str) -> Vec<i64> {
-    let mut res = Vec::new();
-    let file = File::open(path).expect("input file not found");
-    let reader = BufReader::new(file);
-    for line in reader.lines().filter_map(|l| l.ok()) {
-        let mut num = 0;
-        for (i, char) in line.chars().rev().enumerate() {
-            let base = i64::pow(5, i as u32);
-            let digit = match char.to_digit(5) {
-                Some(n) => n as i64,
-                None => match char {
-                    '=' => -2,
-                    '-' => -1,
-                    _ => panic!("unknown character"),
+use aoc_2022::answers::{Answers, Verdict, ANSWERS_PATH};
+use aoc_2022::bench::{bench, table};
+use aoc_2022::days::day01::{top_elves, Inventory};
+use aoc_2022::days::day02::{Day02, Rules, Symbol};
+use aoc_2022::diag::set_verbose;
+use aoc_2022::export::{Canvas, GifWriter, Image, Palette};
+use aoc_2022::generate::generate_to;
+use aoc_2022::par;
+use aoc_2022::report::Record;
+use aoc_2022::store::{Check, Key, Store, YEAR};
+use aoc_2022::viz::{animation, stdin_controls, Player, Scene, ANIMATED_DAYS};
+use aoc_2022::{input_path, solve, Solution, N_DAYS, N_PARTS};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::fs;
+use std::io::{BufRead, BufReader, Cursor, Write};
+use std::process::ExitCode;
+
+/// Runner for my 2022 Advent of Code solutions.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    /// Prints the diagnostics of the solvers to stderr (they are suppressed by default).
+    #[arg(long, short, global = true)]
+    verbose: bool,
+    /// Reads the inputs (and answers) of a profile of the input store instead of data/.
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    /// Number of threads that solve the puzzles (by default, one per CPU).
+    #[arg(long, short, global = true, default_value_t = 0)]
+    jobs: usize,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solves the puzzle of one day (or of every day with --all).
+    Run(RunArgs),
+    /// Checks the answers of my inputs against the answers file.
+    Verify(VerifyArgs),
+    /// Times the parsing and both parts of one day (or of every day).
+    Bench(BenchArgs),
+    /// Plays the simulation of one day step by step in the terminal.
+    Viz(VizArgs),
+    /// Saves the simulation of one day as PNG pictures or as an animated GIF.
+    Export(ExportArgs),
+    /// Manages the input store (see --profile).
+    Inputs(InputsArgs),
+    /// Prints a random input for the puzzle of one day.
+    Gen(GenArgs),
+    /// Answers questions about the calories carried by the elves of day 1.
+    Calories(CaloriesArgs),
+    /// Plays the strategy guide of day 2 with the rules of any game like rock, paper, scissors.
+    Rps(RpsArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day of the puzzle to solve.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64), required_unless_present = "all")]
+    day: Option<u8>,
+    /// Solves every day. Days are solved in parallel (see --jobs), but printed in order.
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+    /// Only solves the given part of the puzzle.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=N_PARTS as i64))]
+    part: Option<u8>,
+    /// Input file (by default, data/NN_input.txt or the input of the profile).
+    #[arg(long)]
+    input: Option<String>,
+    /// Output format of the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One line per answer, for humans.
+    Text,
+    /// One JSON object per answer with the day, part, answer, and elapsed seconds.
+    /// With --all, days share the threads, so use --jobs 1 (or bench) for precise timings.
+    Json,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Only verifies the given day (by default, every day).
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64))]
+    day: Option<u8>,
+    /// Only verifies the given part of the puzzles.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=N_PARTS as i64))]
+    part: Option<u8>,
+    /// File with the known answers (by default, data/answers.toml or the answers of the profile).
+    #[arg(long)]
+    answers: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// Day of the puzzle to time (by default, every day).
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64))]
+    day: Option<u8>,
+    /// Number of runs of every day.
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Input file (by default, data/NN_input.txt or the input of the profile). Requires a day.
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct VizArgs {
+    /// Day of the simulation to play.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64))]
+    day: u8,
+    /// Part of the puzzle whose simulation we play.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=N_PARTS as i64))]
+    part: u8,
+    /// Input file (by default, data/NN_input.txt or the input of the profile).
+    #[arg(long)]
+    input: Option<String>,
+    /// Steps per second.
+    #[arg(long, default_value_t = 10.)]
+    fps: f64,
+    /// Starts paused, so you can go step by step from the beginning.
+    #[arg(long)]
+    paused: bool,
+}
+
+#[derive(Debug, Args)]
+struct ExportArgs {
+    /// Day of the simulation to export.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64))]
+    day: u8,
+    /// Part of the puzzle whose simulation we export.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=N_PARTS as i64))]
+    part: u8,
+    /// Input file (by default, data/NN_input.txt or the input of the profile).
+    #[arg(long)]
+    input: Option<String>,
+    /// Output file. A .gif file gets the whole animation, and a .png file only the last step.
+    /// If a .png path contains `{}`, we save every frame replacing `{}` by the step number.
+    #[arg(long, short)]
+    output: String,
+    /// Side of the square that represents every cell (in pixels).
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..=64))]
+    scale: u16,
+    /// Colours of the cells, with the format `kind=#rrggbb,kind=#rrggbb,...`.
+    #[arg(long)]
+    palette: Option<String>,
+    /// Only every Nth step becomes a frame (the first and last steps are always frames).
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    every: u64,
+    /// Maximum number of steps to simulate (some simulations never end).
+    #[arg(long, default_value_t = 10000)]
+    max_steps: usize,
+    /// Time that every frame of the GIF is shown (in hundredths of a second).
+    #[arg(long, default_value_t = 5)]
+    delay: u16,
+}
+
+#[derive(Debug, Args)]
+struct GenArgs {
+    /// Day of the puzzle.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64))]
+    day: u8,
+    /// Seed of the random numbers. The same seed always gives the same input.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Size of the input (its meaning depends on the day; by default, similar to the real inputs).
+    #[arg(long)]
+    size: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+struct CaloriesArgs {
+    /// Input file (by default, data/01_input.txt or the input of the profile).
+    #[arg(long, global = true)]
+    input: Option<String>,
+    #[command(subcommand)]
+    query: CaloriesQuery,
+}
+
+#[derive(Debug, Subcommand)]
+enum CaloriesQuery {
+    /// Prints the elves with the most calories, from best to worst.
+    /// The input is read line by line, so it can be larger than the available memory.
+    Top {
+        /// Number of elves.
+        #[arg(default_value_t = 3)]
+        k: usize,
+    },
+    /// Prints the number of elves and the total, minimum, mean, median, and maximum calories.
+    Stats,
+    /// Prints percentiles of the calories per elf (nearest-rank method).
+    Percentile {
+        /// Percentiles, from 0 to 100.
+        #[arg(required = true, value_parser = parse_percentile)]
+        p: Vec<f64>,
+    },
+    /// Prints how many elves carry calories within ranges of the same width.
+    Histogram {
+        /// Number of ranges.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        buckets: u32,
+    },
+    /// Prints the elves with more calories than a threshold, from best to worst.
+    Above { threshold: usize },
+    /// Prints the rank of an elf (the elf with the most calories is number 1).
+    Rank {
+        /// Position of the elf in the input (the first elf is 0).
+        elf: usize,
+    },
+}
+
+/// It parses a percentile of the `calories` command.
+fn parse_percentile(p: &str) -> Result<f64, String> {
+    match p.parse::<f64>() {
+        Ok(p) if (0. ..=100.).contains(&p) => Ok(p),
+        _ => Err("percentiles must be numbers from 0 to 100".to_string()),
+    }
+}
+
+#[derive(Debug, Args)]
+struct RpsArgs {
+    /// Input file (by default, data/02_input.txt or the input of the profile).
+    #[arg(long, global = true)]
+    input: Option<String>,
+    /// TOML file with the rules of the game (by default, the classic rock, paper, scissors).
+    #[arg(long, global = true)]
+    rules: Option<String>,
+    #[command(subcommand)]
+    query: RpsQuery,
+}
+
+#[derive(Debug, Subcommand)]
+enum RpsQuery {
+    /// Prints my total score if my column is a move (part 1) and if it is an outcome (part 2).
+    Score,
+    /// Prints the best response to every move of the elf and the best possible score.
+    Best,
+    /// Prints the score of every meaning of the letters of my column, from best to worst.
+    Decodings {
+        /// Also prints the meanings whose score is the closest to this one.
+        #[arg(long)]
+        target: Option<usize>,
+    },
+}
+
+#[derive(Debug, Args)]
+struct InputsArgs {
+    #[command(subcommand)]
+    command: InputsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum InputsCommand {
+    /// Copies every input file of a directory (e.g., 01_input.txt or day01.txt) to a profile.
+    Import {
+        /// Directory with the input files.
+        dir: String,
+        /// Year of the inputs.
+        #[arg(long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Copies one input file to a profile.
+    Add {
+        /// Day of the input.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=N_DAYS as i64))]
+        day: u8,
+        /// Input file.
+        file: String,
+        /// Year of the input.
+        #[arg(long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Checks the inputs of a profile against their checksums.
+    Check {
+        /// Year of the inputs.
+        #[arg(long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Lists the profiles of the store.
+    List,
+}
+
+/// It returns the parts selected by the user (by default, all of them).
+fn selected_parts(part: Option<u8>) -> Vec<usize> {
+    match part {
+        Some(part) => vec![part as usize],
+        None => (1..N_PARTS + 1).collect(),
+    }
+}
+
+/// It returns the days selected by the user (by default, all of them).
+fn selected_days(day: Option<u8>) -> Vec<usize> {
+    match day {
+        Some(day) => vec![day as usize],
+        None => (1..N_DAYS + 1).collect(),
+    }
+}
+
+/// It reads the input of a day: the given file, the input of the profile, or data/NN_input.txt.
+fn read_input(day: usize, file: Option<&str>, profile: Option<&str>) -> Result<String, String> {
+    match (file, profile) {
+        (None, Some(profile)) => Store::default()
+            .get(&Key::new(profile, day))
+            .map_err(|e| e.to_string()),
+        (file, _) => {
+            let path = file.map_or_else(|| input_path(day), str::to_string);
+            fs::read_to_string(&path).map_err(|e| format!("unable to read {}: {}", path, e))
+        }
+    }
+}
+
+/// It opens the input of a day for reading it line by line.
+/// Unlike `read_input`, files are not loaded into memory (inputs of the store are, though).
+fn open_input(
+    day: usize,
+    file: Option<&str>,
+    profile: Option<&str>,
+) -> Result<Box<dyn BufRead>, String> {
+    match (file, profile) {
+        (None, Some(_)) => Ok(Box::new(Cursor::new(read_input(day, file, profile)?))),
+        (file, _) => {
+            let path = file.map_or_else(|| input_path(day), str::to_string);
+            let file =
+                fs::File::open(&path).map_err(|e| format!("unable to read {}: {}", path, e))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+    }
+}
+
+/// It prints the answer of a part. Multi-line answers start in a new line.
+fn print_answer(day: usize, part: usize, answer: &str) {
+    match answer.contains('\n') {
+        true => println!("Day {:02} part {}:\n{}", day, part, answer.trim_end()),
+        false => println!("Day {:02} part {}: {}", day, part, answer),
+    }
+}
+
+/// It solves the required parts of a day.
+/// If a part fails, we keep the records of the previous parts and the error.
+fn run_day(day: usize, parts: &[usize], input: &str) -> (Vec<Record>, Option<String>) {
+    let mut records = Vec::new();
+    for &part in parts {
+        match Record::solve(day, part, input) {
+            Ok(record) => records.push(record),
+            Err(e) => return (records, Some(format!("day {} part {}: {}", day, part, e))),
+        }
+    }
+    (records, None)
+}
+
+fn run(args: RunArgs, profile: Option<&str>) -> Result<(), String> {
+    let parts = selected_parts(args.part);
+    // Days are solved at the same time, but we print them in order
+    let days = par::map(&selected_days(args.day), |&day| {
+        match read_input(day, args.input.as_deref(), profile) {
+            Ok(input) => run_day(day, &parts, &input),
+            Err(e) => (Vec::new(), Some(e)),
+        }
+    });
+    // A failed day does not hide the answers of the rest: we report the errors at the end
+    let mut errors = Vec::new();
+    for (records, error) in days {
+        for record in records {
+            match args.format {
+                Format::Text => print_answer(record.day, record.part, &record.answer),
+                Format::Json => println!("{}", record.to_json()),
+            }
+        }
+        errors.extend(error);
+    }
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join("\nerror: ")),
+    }
+}
+
+/// It formats a failed verification. Multi-line answers are shown one after the other.
+fn show_failure(expected: &str, answer: &str) -> String {
+    match expected.contains('\n') || answer.contains('\n') {
+        true => format!(
+            "FAIL\n-- expected --\n{}\n-- got --\n{}",
+            expected.trim_matches('\n').trim_end(),
+            answer.trim_matches('\n').trim_end()
+        ),
+        false => format!("FAIL (expected {}, got {})", expected, answer),
+    }
+}
+
+fn verify(args: VerifyArgs, profile: Option<&str>) -> Result<(), String> {
+    let path = match (args.answers, profile) {
+        (Some(path), _) => path,
+        (None, Some(profile)) => Store::default()
+            .answers_path(profile, YEAR)
+            .map_err(|e| e.to_string())?
+            .display()
+            .to_string(),
+        (None, None) => ANSWERS_PATH.to_string(),
+    };
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("unable to read {}: {}", path, e))?;
+    let answers = Answers::parse(&content).map_err(|e| format!("{}: {}", path, e))?;
+    let parts = selected_parts(args.part);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    // Days are solved at the same time, but we report them in order
+    let days = selected_days(args.day);
+    let answers_of_days = par::map(&days, |&day| {
+        let input = read_input(day, None, profile);
+        parts
+            .iter()
+            .map(|&part| match &input {
+                Ok(input) => solve(day, part, input).map_err(|e| e.to_string()),
+                Err(e) => Err(e.clone()),
+            })
+            .collect::<Vec<_>>()
+    });
+    for (day, answers_of_day) in days.into_iter().zip(answers_of_days) {
+        for (&part, answer) in parts.iter().zip(answers_of_day) {
+            let report = match answer {
+                Ok(answer) => match answers.verify(day, part, &answer) {
+                    Verdict::Pass => {
+                        passed += 1;
+                        "PASS".to_string()
+                    }
+                    Verdict::Fail { expected } => {
+                        failed += 1;
+                        show_failure(&expected, &answer)
+                    }
+                    Verdict::Missing => {
+                        missing += 1;
+                        match answer.contains('\n') {
+                            true => format!("MISSING (got)\n{}", answer.trim_end()),
+                            false => format!("MISSING (got {})", answer),
+                        }
+                    }
                 },
+                Err(e) => {
+                    failed += 1;
+                    format!("FAIL ({})", e)
+                }
             };
-            num += digit * base;
-        }
-        res.push(num);
-    }
-    res
-}
-
-fn dec_to_snafu(mut num: i64) -> String {
-    // First, we translate the number to a 5 base
-    let mut numeric = Vec::new();
-    while num != 0 {
-        numeric.push(num % 5);
-        num /= 5;
-    }
-    numeric.push(0); // We add an extra digit for convenience in case of an overflow
-                     // Now it is time to modify the base to snafu
-    for i in 0..numeric.len() - 1 {
-        // First, we correct any potential overflow due to carries
-        while numeric[i] >= 5 {
-            numeric[i] -= 5;
-            numeric[i + 1] += 1;
-        }
-        // Next, we change the digit to negative values if needed
-        if numeric[i] >= 3 {
-            numeric[i + 1] += 1; // We need to add 1 to the next digit!
-            numeric[i] = match numeric[i] {
-                3 => -2,
-                4 => -1,
-                _ => panic!("this should never happen"),
+            println!("Day {:02} part {}: {}", day, part, report);
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{} answers do not match", failed)),
+    }
+}
+
+fn run_bench(args: BenchArgs, profile: Option<&str>) -> Result<(), String> {
+    let mut benches = Vec::new();
+    for day in selected_days(args.day) {
+        let input = read_input(day, args.input.as_deref(), profile)?;
+        let res =
+            bench(day, &input, args.runs as usize).map_err(|e| format!("day {}: {}", day, e))?;
+        benches.push(res);
+    }
+    print!("{}", table(&benches));
+    Ok(())
+}
+
+fn viz(args: VizArgs, profile: Option<&str>) -> Result<(), String> {
+    let (day, part) = (args.day as usize, args.part as usize);
+    let input = read_input(day, args.input.as_deref(), profile)?;
+    let mut animation = animation(day, part, &input)
+        .map_err(|e| format!("day {} part {}: {}", day, part, e))?
+        .ok_or_else(|| format!("day {} has no animation (try {:?})", day, ANIMATED_DAYS))?;
+    let mut player = Player::new(args.fps).paused(args.paused);
+    player
+        .play(
+            animation.as_mut(),
+            &stdin_controls(),
+            &mut std::io::stdout().lock(),
+        )
+        .map_err(|e| format!("unable to draw the animation: {}", e))?;
+    Ok(())
+}
+
+fn export(args: ExportArgs, profile: Option<&str>) -> Result<(), String> {
+    let (day, part) = (args.day as usize, args.part as usize);
+    let input = read_input(day, args.input.as_deref(), profile)?;
+    let new_animation = || {
+        animation(day, part, &input)
+            .map_err(|e| format!("day {} part {}: {}", day, part, e))?
+            .ok_or_else(|| format!("day {} has no animation (try {:?})", day, ANIMATED_DAYS))
+    };
+    let mut animation = new_animation()?;
+    // Palettes may only paint the cells of this animation
+    let palette = match &args.palette {
+        Some(palette) => Palette::parse(palette, animation.kinds())
+            .map_err(|e| format!("invalid palette: {}", e))?,
+        None => Palette::new(),
+    };
+
+    let output = &args.output;
+    let gif = output.ends_with(".gif");
+    let numbered = output.ends_with(".png") && output.contains("{}");
+    if !gif && !output.ends_with(".png") {
+        return Err(format!(
+            "{}: the output must be a .png or a .gif file",
+            output
+        ));
+    }
+    let save_png = |image: &Image, path: &str| {
+        if image.width() == 0 || image.height() == 0 {
+            return Err(format!("{}: the picture is empty", path));
+        }
+        let file =
+            fs::File::create(path).map_err(|e| format!("unable to create {}: {}", path, e))?;
+        image
+            .write_png(std::io::BufWriter::new(file))
+            .map_err(|e| format!("unable to write {}: {}", path, e))
+    };
+
+    let scale = args.scale as usize;
+    let steps = if gif {
+        // The first pass finds the size and colours of the animation, and the second one
+        // encodes every frame as soon as it is drawn
+        let mut canvas = Canvas::new(palette.background());
+        play(animation.as_mut(), &palette, &args, |_, image| {
+            canvas.fit(&image);
+            Ok(())
+        })?;
+        let file =
+            fs::File::create(output).map_err(|e| format!("unable to create {}: {}", output, e))?;
+        let mut writer = GifWriter::new(std::io::BufWriter::new(file), canvas, scale, args.delay)
+            .map_err(|e| format!("unable to write {}: {}", output, e))?;
+        play(new_animation()?.as_mut(), &palette, &args, |_, image| {
+            writer
+                .write(&image)
+                .map_err(|e| format!("unable to write {}: {}", output, e))
+        })?
+    } else if numbered {
+        play(animation.as_mut(), &palette, &args, |step, image| {
+            save_png(
+                &image.scale(scale),
+                &output.replace("{}", &step.to_string()),
+            )
+        })?
+    } else {
+        let mut steps = 0;
+        while steps < args.max_steps && animation.step() {
+            steps += 1;
+        }
+        save_png(&animation.render(&palette).scale(scale), output)?;
+        steps
+    };
+    eprintln!("{} steps exported to {}", steps, output);
+    Ok(())
+}
+
+/// It plays an animation up to `--max-steps` steps, and draws the first step, every Nth step,
+/// and the last step (see `--every`). It returns the number of steps.
+fn play(
+    animation: &mut dyn Scene,
+    palette: &Palette,
+    args: &ExportArgs,
+    mut keep: impl FnMut(usize, Image) -> Result<(), String>,
+) -> Result<usize, String> {
+    keep(0, animation.render(palette))?;
+    let mut steps = 0;
+    while steps < args.max_steps && animation.step() {
+        steps += 1;
+        if (steps as u64).is_multiple_of(args.every) {
+            keep(steps, animation.render(palette))?;
+        }
+    }
+    if steps > 0 && !(steps as u64).is_multiple_of(args.every) {
+        keep(steps, animation.render(palette))?;
+    }
+    Ok(steps)
+}
+
+fn inputs(args: InputsArgs, profile: Option<&str>) -> Result<(), String> {
+    let store = Store::default();
+    let profile = || profile.ok_or_else(|| "this command requires --profile".to_string());
+    match args.command {
+        InputsCommand::Import { dir, year } => {
+            let days = store
+                .import(profile()?, year, dir.as_ref())
+                .map_err(|e| e.to_string())?;
+            println!("{} inputs imported to {}", days.len(), profile()?);
+        }
+        InputsCommand::Add { day, file, year } => {
+            let content =
+                fs::read_to_string(&file).map_err(|e| format!("unable to read {}: {}", file, e))?;
+            let key = Key {
+                profile: profile()?.to_string(),
+                year,
+                day: day as usize,
             };
+            let checksum = store.insert(&key, &content).map_err(|e| e.to_string())?;
+            println!("{}: {}", key, checksum);
+        }
+        InputsCommand::Check { year } => {
+            let checks = store.check(profile()?, year).map_err(|e| e.to_string())?;
+            let mut n_invalid = 0;
+            for (day, check) in checks {
+                let report = match check {
+                    Check::Valid => "OK".to_string(),
+                    Check::Untracked => "UNTRACKED (no checksum)".to_string(),
+                    Check::Missing => {
+                        n_invalid += 1;
+                        "MISSING".to_string()
+                    }
+                    Check::Corrupted { expected, found } => {
+                        n_invalid += 1;
+                        format!("MODIFIED (expected {}, found {})", expected, found)
+                    }
+                };
+                println!("Day {:02}: {}", day, report);
+            }
+            if n_invalid > 0 {
+                return Err(format!("{} inputs do not match their checksums", n_invalid));
+            }
+        }
+        InputsCommand::List => {
+            for profile in store.profiles().map_err(|e| e.to_string())? {
+                println!("{}", profile);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn gen(args: GenArgs) -> Result<(), String> {
+    // Huge inputs are written as they are generated, so they never need to fit in memory
+    let mut out = std::io::BufWriter::new(std::io::stdout().lock());
+    generate_to(args.day as usize, args.seed, args.size, &mut out)
+        .and_then(|_| out.flush())
+        .map_err(|e| format!("unable to write the input: {}", e))
+}
+
+fn calories(args: CaloriesArgs, profile: Option<&str>) -> Result<(), String> {
+    let file = args.input.as_deref();
+    // Only the top elves can be streamed, the rest of queries need the whole inventory
+    if let CaloriesQuery::Top { k } = args.query {
+        let elves = top_elves(open_input(1, file, profile)?, k);
+        for elf in elves.map_err(|e| format!("day 1: {}", e))? {
+            println!("elf {}: {}", elf.index, elf.calories);
         }
-        assert!(numeric[numeric.len() - 1] < 3); // Just checking the the last digit is correct
+        return Ok(());
     }
-    // Finally, we iterate in reverse order to add the digit in the string
-    let mut res = String::new();
-    for &digit in numeric.iter().rev() {
-        res.push(match digit {
-            -2 => '=',
-            -1 => '-',
-            n => char::from_digit(n as u32, 5).unwrap(),
-        })
+    let inventory =
+        Inventory::parse(&read_input(1, file, profile)?).map_err(|e| format!("day 1: {}", e))?;
+    if inventory.is_empty() {
+        return Err("day 1: the inventory has no elves".to_string());
     }
-    String::from(res.trim_start_matches('0')) // We trim the starting zero just in case
+    match args.query {
+        CaloriesQuery::Top { .. } => unreachable!("top elves are streamed"),
+        CaloriesQuery::Stats => {
+            let total = inventory
+                .total()
+                .ok_or("day 1: the total calories do not fit in a usize")?;
+            println!("elves: {}", inventory.len());
+            println!("total: {}", total);
+            println!("min: {}", inventory.min().unwrap_or_default());
+            println!("mean: {:.2}", inventory.mean().unwrap_or_default());
+            println!("median: {}", inventory.median().unwrap_or_default());
+            println!("max: {}", inventory.max().unwrap_or_default());
+        }
+        CaloriesQuery::Percentile { p } => {
+            for p in p {
+                println!("p{}: {}", p, inventory.percentile(p).unwrap_or_default());
+            }
+        }
+        CaloriesQuery::Histogram { buckets } => {
+            let histogram = inventory.histogram(buckets as usize);
+            let most = histogram.iter().map(|b| b.count).max().unwrap_or_default();
+            // Bars are at most 50 characters long
+            for bucket in histogram {
+                let bar = "#".repeat((bucket.count * 50).div_ceil(most.max(1)));
+                let range = format!("{}-{}", bucket.start, bucket.end);
+                println!("{:>13}: {:>6} {}", range, bucket.count, bar);
+            }
+        }
+        CaloriesQuery::Above { threshold } => {
+            for elf in inventory.above(threshold) {
+                println!("elf {}: {}", elf.index, elf.calories);
+            }
+        }
+        CaloriesQuery::Rank { elf } => {
+            let rank = inventory.rank(elf).ok_or_else(|| {
+                format!("there is no elf {} (there are {})", elf, inventory.len())
+            })?;
+            let calories = inventory.calories(elf).unwrap_or_default();
+            println!(
+                "elf {}: rank {} of {} ({} calories)",
+                elf,
+                rank,
+                inventory.len(),
+                calories
+            );
+        }
+    }
+    Ok(())
 }
 
-fn main() {
-    let scenario = read_input("data/25_input.txt");
-    println!("{:?}", &scenario);
-    let sum: i64 = scenario.iter().sum();
-    println!("{}", dec_to_snafu(sum));
+fn rps(args: RpsArgs, profile: Option<&str>) -> Result<(), String> {
+    let rules = match &args.rules {
+        Some(path) => {
+            let content =
+                fs::read_to_string(path).map_err(|e| format!("unable to read {}: {}", path, e))?;
+            Rules::parse(&content).map_err(|e| format!("{}: {}", path, e))?
+        }
+        None => Rules::default(),
+    };
+    let input = read_input(2, args.input.as_deref(), profile)?;
+    match args.query {
+        RpsQuery::Score => {
+            // A guide may only make sense with one of the interpretations (e.g., my column has
+            // more moves than outcomes), so we print every part that we can solve
+            let day = Day02::with_rules(rules, &input).map_err(|e| format!("day 2: {}", e))?;
+            let answers = [day.part1(), day.part2()];
+            let mut errors = Vec::new();
+            for (part, answer) in (1..).zip(answers) {
+                match answer {
+                    Ok(points) => print_answer(2, part, &points.to_string()),
+                    Err(e) => errors.push(format!("day 2 part {}: {}", part, e)),
+                }
+            }
+            if !errors.is_empty() {
+                return Err(errors.join("\nerror: "));
+            }
+        }
+        RpsQuery::Best => {
+            let plays = rules
+                .optimal_plays(&input)
+                .map_err(|e| format!("day 2: {}", e))?;
+            for elf in (0..rules.moves().len()).map(Symbol) {
+                let me = rules.best_response(elf);
+                let (elf_move, my_move) = (rules.get(elf), rules.get(me));
+                let points = rules.match_points(elf, me);
+                println!(
+                    "{} ({}): {} ({} points)",
+                    elf_move.elf, elf_move.name, my_move.name, points
+                );
+            }
+            println!("best score: {}", rules.total_points(&plays));
+        }
+        RpsQuery::Decodings { target } => {
+            let decodings = rules
+                .decodings(&input)
+                .map_err(|e| format!("day 2: {}", e))?;
+            for (decoding, score) in &decodings {
+                println!("{}: {}", rules.describe(decoding), score);
+            }
+            if let Some(target) = target {
+                // Several decodings may be equally close
+                let distance = |score: usize| score.abs_diff(target);
+                let best = decodings.iter().map(|&(_, s)| distance(s)).min();
+                println!("closest to {}:", target);
+                for (decoding, score) in decodings
+                    .iter()
+                    .filter(|&&(_, s)| Some(distance(s)) == best)
+                {
+                    println!(
+                        "{}: {} (off by {})",
+                        rules.describe(decoding),
+                        score,
+                        distance(*score)
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    set_verbose(cli.verbose);
+    let profile = cli.profile.as_deref();
+    if let Err(e) = par::set_jobs(cli.jobs) {
+        eprintln!("error: unable to start the threads: {}", e);
+        return ExitCode::FAILURE;
+    }
+    let res = match cli.command {
+        Command::Run(args) => run(args, profile),
+        Command::Verify(args) => verify(args, profile),
+        Command::Bench(args) => run_bench(args, profile),
+        Command::Viz(args) => viz(args, profile),
+        Command::Export(args) => export(args, profile),
+        Command::Inputs(args) => inputs(args, profile),
+        Command::Gen(args) => gen(args),
+        Command::Calories(args) => calories(args, profile),
+        Command::Rps(args) => rps(args, profile),
+    };
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,32 @@
+//! Parallel execution, shared by the runner and the solvers.
+//!
+//! Everything runs in the same work-stealing thread pool: the runner solves several days at the
+//! same time, and the solvers split their own work (e.g., the blueprints of day 19) in smaller
+//! tasks. Idle threads steal pending tasks from busy ones, so nested tasks never oversubscribe
+//! the machine. Results always come back in the same order as the inputs, so the output does
+//! not depend on the number of threads.
+
+use rayon::prelude::*;
+
+/// It sets the number of threads of the pool. Zero means one thread per CPU.
+/// It must be called before running anything in parallel, and only once.
+pub fn set_jobs(n: usize) -> Result<(), String> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(n)
+        .build_global()
+        .map_err(|e| e.to_string())
+}
+
+/// It applies `f` to every item in parallel. Results keep the order of the items.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    items.par_iter().map(f).collect()
+}
+
+/// It applies `f` to every number of a range in parallel and returns the first `Some` result
+/// (i.e., the one of the smallest number), no matter which thread found it first.
+pub fn find_first<R: Send>(
+    range: std::ops::Range<i32>,
+    f: impl Fn(i32) -> Option<R> + Sync + Send,
+) -> Option<R> {
+    range.into_par_iter().find_map_first(f)
+}
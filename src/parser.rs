@@ -0,0 +1,674 @@
+//! Tiny parser combinators for the formats of the puzzle inputs.
+//!
+//! A parser is anything that implements [`Parser`], i.e., any function that takes an [`Input`]
+//! and returns the parsed value together with the rest of the input. Small parsers (numbers,
+//! keywords, lines...) are combined into bigger ones (lists, records, blocks...), so the code
+//! of every day describes the format instead of slicing strings by hand.
+//!
+//! Errors always point to the line and column of the problem. If several alternatives fail,
+//! we report the one that went further, which is usually the one that the author meant.
+//!
+//! ```
+//! use aoc_2022::parser::{complete, delimited, separated, tag, unsigned};
+//!
+//! let list = delimited(tag("["), separated(unsigned::<u32>(), tag(",")), tag("]"));
+//! assert_eq!(complete(&list, "[1,2,3]").unwrap(), vec![1, 2, 3]);
+//! assert_eq!(
+//!     complete(&list, "[1,2;3]").unwrap_err().to_string(),
+//!     "line 1, column 5: unexpected character ';'"
+//! );
+//! ```
+
+use crate::error::location_of;
+use crate::grid::Grid;
+use crate::AocError;
+use std::str::FromStr;
+
+/// Text that is being parsed: the whole text and the position of the next character.
+/// Inputs are cheap copies, so backtracking is just reusing an old input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Input<'a> {
+    /// It creates an input that starts at the beginning of the text.
+    pub fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    /// It returns the text that has not been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    /// It returns true if there is nothing left to parse.
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// It returns the next character (if any).
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// It returns the 1-based line and column of the next character.
+    pub fn location(&self) -> (usize, usize) {
+        location_of(self.text, self.rest())
+    }
+
+    /// It skips the next `n` bytes.
+    fn advance(self, n: usize) -> Self {
+        Self {
+            text: self.text,
+            pos: self.pos + n,
+        }
+    }
+
+    /// It returns a failure because we expected something else here.
+    /// The message depends on what we find: a character, the end of the line, or the end of the input.
+    pub fn expected(&self, what: impl Into<String>) -> Failure {
+        let (line, column) = self.location();
+        let error = match self.peek() {
+            None => AocError::unexpected_end(line, what),
+            Some('\n' | '\r') => AocError::parse(line, column, format!("expected {}", what.into())),
+            Some(c) => AocError::unexpected_char(line, column, c),
+        };
+        Failure {
+            pos: self.pos,
+            error,
+        }
+    }
+
+    /// It returns a failure with a custom message that points to the next character.
+    pub fn error(&self, msg: impl Into<String>) -> Failure {
+        let (line, column) = self.location();
+        Failure {
+            pos: self.pos,
+            error: AocError::parse(line, column, msg),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+/// A parser that could not parse its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// How far the parser went before failing. Parsers that fail at the position
+    /// where they started did not consume anything, so we can try something else.
+    pos: usize,
+    /// What went wrong.
+    error: AocError,
+}
+
+impl From<Failure> for AocError {
+    fn from(failure: Failure) -> Self {
+        failure.error
+    }
+}
+
+/// Result of a parser: the parsed value and the rest of the input.
+pub type PResult<'a, T> = Result<(T, Input<'a>), Failure>;
+
+/// Common interface of all the parsers. Functions from [`Input`] to [`PResult`] are parsers.
+pub trait Parser<'a, T> {
+    /// It parses the beginning of the input.
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T>;
+
+    /// It transforms the parsed value.
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| self.parse(input).map(|(value, rest)| (f(value), rest))
+    }
+
+    /// It transforms the parsed value with a function that may reject it.
+    /// Errors point to the beginning of the value.
+    fn try_map<U, E: Into<String>>(self, f: impl Fn(T) -> Result<U, E>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| {
+            let (value, rest) = self.parse(input)?;
+            match f(value) {
+                Ok(value) => Ok((value, rest)),
+                // We consumed the value, so the failure must not be recoverable
+                Err(msg) => Err(Failure {
+                    pos: rest.pos.max(input.pos + 1),
+                    error: input.error(msg).error,
+                }),
+            }
+        }
+    }
+
+    /// It describes what the parser expects, so failures at the start of the input say so.
+    fn label(self, what: &'static str) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| match self.parse(input) {
+            Err(failure) if failure.pos == input.pos => Err(input.expected(what)),
+            res => res,
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> PResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// It parses the whole text (or the rest of an input). Only blank space may follow what the parser accepts.
+pub fn complete<'a, T>(
+    parser: &impl Parser<'a, T>,
+    input: impl Into<Input<'a>>,
+) -> Result<T, AocError> {
+    let (value, rest) = parser.parse(input.into())?;
+    let trailing = rest.rest().len() - rest.rest().trim_start().len();
+    let rest = rest.advance(trailing);
+    match rest.is_empty() {
+        true => Ok(value),
+        false => Err(rest.expected("the end of the input").into()),
+    }
+}
+
+/// It parses an exact piece of text.
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| match input.rest().starts_with(tag) {
+        true => Ok((&input.rest()[..tag.len()], input.advance(tag.len()))),
+        false => {
+            // We point to the first character that does not match
+            let matching = input
+                .rest()
+                .char_indices()
+                .zip(tag.chars())
+                .find(|&((_, a), b)| a != b)
+                .map_or(input.rest().len(), |((i, _), _)| i);
+            let error = input.advance(matching).expected(format!("{:?}", tag)).error;
+            Err(Failure {
+                pos: input.pos,
+                error,
+            })
+        }
+    }
+}
+
+/// It parses one character that satisfies a predicate.
+pub fn item_if<'a>(predicate: impl Fn(char) -> bool, what: &'static str) -> impl Parser<'a, char> {
+    move |input: Input<'a>| match input.peek() {
+        Some(c) if predicate(c) => Ok((c, input.advance(c.len_utf8()))),
+        _ => Err(input.expected(what)),
+    }
+}
+
+/// It parses the longest (maybe empty) sequence of characters that satisfy a predicate.
+pub fn take_while<'a>(predicate: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        Ok((&rest[..len], input.advance(len)))
+    }
+}
+
+/// It skips spaces and tabs (but not line breaks).
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    take_while(|c| c == ' ' || c == '\t').map(|_| ())
+}
+
+/// It parses a token with [`FromStr`]. Errors point to the beginning of the token.
+fn token<'a, T: FromStr>(input: Input<'a>, len: usize) -> PResult<'a, T> {
+    let token = &input.rest()[..len];
+    match token.parse() {
+        Ok(value) => Ok((value, input.advance(len))),
+        Err(_) => Err(Failure {
+            pos: input.pos + len,
+            error: input
+                .error(format!(
+                    "invalid {} {:?}",
+                    std::any::type_name::<T>(),
+                    token
+                ))
+                .error,
+        }),
+    }
+}
+
+/// It parses an unsigned integer (i.e., a sequence of digits).
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let len = input.rest().find(|c: char| !c.is_ascii_digit());
+        match len.unwrap_or(input.rest().len()) {
+            0 => Err(input.expected("a number")),
+            len => token(input, len),
+        }
+    }
+}
+
+/// It parses a signed integer (i.e., a sequence of digits with an optional sign).
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let sign = match input.peek() {
+            Some('-' | '+') => 1,
+            _ => 0,
+        };
+        let digits = input.advance(sign).rest();
+        let len = digits.find(|c: char| !c.is_ascii_digit());
+        match len.unwrap_or(digits.len()) {
+            0 => Err(input.advance(sign).expected("a number")),
+            len => token(input, sign + len),
+        }
+    }
+}
+
+/// It parses two things in a row.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: Input<'a>| {
+        let (a, input) = first.parse(input)?;
+        let (b, input) = second.parse(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+/// It parses two things in a row and keeps the second one.
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    pair(first, second).map(|(_, b)| b)
+}
+
+/// It parses two things in a row and keeps the first one.
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    pair(first, second).map(|(a, _)| a)
+}
+
+/// It parses three things in a row and keeps the one in the middle (e.g., a list within brackets).
+pub fn delimited<'a, A, B, C>(
+    open: impl Parser<'a, A>,
+    inner: impl Parser<'a, B>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, B> {
+    preceded(open, terminated(inner, close))
+}
+
+/// It tries a parser and, if it fails, another one.
+/// If both fail, it reports the failure of the parser that went further.
+pub fn either<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| match first.parse(input) {
+        Ok(res) => Ok(res),
+        Err(a) => match second.parse(input) {
+            Ok(res) => Ok(res),
+            Err(b) => Err(match a.pos > b.pos {
+                true => a,
+                false => b,
+            }),
+        },
+    }
+}
+
+/// It tries a parser. If it fails without consuming anything, it returns `None`.
+pub fn optional<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: Input<'a>| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(failure) if failure.pos == input.pos => Ok((None, input)),
+        Err(failure) => Err(failure),
+    }
+}
+
+/// It applies a parser as many times as possible (maybe zero).
+/// It stops when the parser fails without consuming anything.
+pub fn many<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: Input<'a>| {
+        let mut res = Vec::new();
+        while let (Some(value), rest) = optional(|i| parser.parse(i)).parse(input)? {
+            // A parser that consumes nothing would succeed forever
+            if rest == input {
+                break;
+            }
+            res.push(value);
+            input = rest;
+        }
+        Ok((res, input))
+    }
+}
+
+/// It parses a list of items separated by `separator` (maybe empty).
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    optional(separated1(item, separator)).map(Option::unwrap_or_default)
+}
+
+/// It parses a non-empty list of items separated by `separator`.
+pub fn separated1<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let (first, mut input) = item.parse(input)?;
+        let mut res = vec![first];
+        // Once we find a separator, there must be another item
+        while let (Some(_), rest) = optional(|i| separator.parse(i)).parse(input)? {
+            let (value, rest) = item.parse(rest)?;
+            res.push(value);
+            input = rest;
+        }
+        Ok((res, input))
+    }
+}
+
+/// It parses the end of a line (or the end of the input).
+pub fn eol<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        if rest.is_empty() {
+            Ok(((), input))
+        } else if rest.starts_with('\n') {
+            Ok(((), input.advance(1)))
+        } else if rest.starts_with("\r\n") {
+            Ok(((), input.advance(2)))
+        } else {
+            Err(input.expected("the end of the line"))
+        }
+    }
+}
+
+/// It parses a whole line. Trailing spaces are ignored.
+pub fn line<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    terminated(parser, pair(spaces(), eol()))
+}
+
+/// It returns true if the input is at a blank line (or at the end of the input).
+fn at_blank_line(input: Input<'_>) -> bool {
+    let line = input.rest().split('\n').next().unwrap_or_default();
+    line.trim().is_empty()
+}
+
+/// It skips blank lines.
+pub fn blank_lines<'a>() -> impl Parser<'a, ()> {
+    move |mut input: Input<'a>| {
+        while !input.is_empty() && at_blank_line(input) {
+            input = line(spaces()).parse(input)?.1;
+        }
+        Ok(((), input))
+    }
+}
+
+/// It parses one line with the parser for every line until a blank line (or the end of the input).
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let parser = line(parser);
+    move |mut input: Input<'a>| {
+        let mut res = Vec::new();
+        while !at_blank_line(input) {
+            let (value, rest) = parser.parse(input)?;
+            res.push(value);
+            input = rest;
+        }
+        Ok((res, input))
+    }
+}
+
+/// It parses blocks of lines separated by blank lines until the end of the input.
+/// `block` parses the lines of a block, and every block must be followed by a blank line.
+pub fn blocks<'a, T>(block: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let mut res = Vec::new();
+        let (_, mut input) = blank_lines().parse(input)?;
+        while !input.is_empty() {
+            let (value, rest) = block.parse(input)?;
+            if !at_blank_line(rest) {
+                return Err(rest.error("expected a blank line after the block"));
+            }
+            res.push(value);
+            input = blank_lines().parse(rest)?.1;
+        }
+        Ok((res, input))
+    }
+}
+
+/// It parses a line that starts with a keyword followed by a value (e.g., `Test: divisible by 23`).
+/// Spaces around the keyword are ignored, so records may be indented.
+pub fn field<'a, T>(keyword: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    line(preceded(
+        pair(spaces(), tag(keyword)),
+        preceded(spaces(), value),
+    ))
+}
+
+/// It parses a keyword followed by a value within a line (e.g., `from 1` in `move 1 from 2 to 3`).
+/// Spaces around the keyword are ignored.
+pub fn keyword<'a, T>(keyword: &'static str, value: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    preceded(pair(spaces(), tag(keyword)), preceded(spaces(), value))
+}
+
+/// It parses a map with one character per cell until a blank line (see [`Grid::parse`]).
+pub fn grid<'a, T>(cell: impl Fn(char) -> Option<T>) -> impl Parser<'a, Grid<T>> {
+    move |input: Input<'a>| {
+        let (rows, rest) = lines(take_while(|c| c != '\n' && c != '\r')).parse(input)?;
+        let len = rows.last().map_or(0, |row| {
+            row.as_ptr() as usize + row.len() - input.rest().as_ptr() as usize
+        });
+        let text = &input.rest()[..len];
+        match Grid::parse(text, &cell) {
+            Ok(grid) => Ok((grid, rest)),
+            Err(error) => Err(Failure {
+                pos: input.pos + len.max(1),
+                error: error.within(input.text, text),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Point;
+
+    /// It returns the error message of parsing the whole text.
+    fn error<'a, T: std::fmt::Debug>(parser: impl Parser<'a, T>, text: &'a str) -> String {
+        complete(&parser, text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn tags() {
+        assert_eq!(complete(&tag("move"), "move").unwrap(), "move");
+        assert_eq!(
+            error(tag("move"), "mode"),
+            "line 1, column 3: unexpected character 'd'"
+        );
+        assert_eq!(
+            error(tag("move"), "mov"),
+            "line 1: unexpected end of input, expected \"move\""
+        );
+        assert_eq!(
+            error(pair(line(tag("a")), tag("move")), "a\nmoxe"),
+            "line 2, column 3: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn signed_numbers() {
+        assert_eq!(complete(&signed::<i32>(), "-12").unwrap(), -12);
+        assert_eq!(complete(&signed::<i32>(), "+7").unwrap(), 7);
+        assert_eq!(complete(&signed::<i32>(), "42").unwrap(), 42);
+        assert_eq!(complete(&signed::<i8>(), "-128").unwrap(), i8::MIN);
+        assert_eq!(
+            error(signed::<i32>(), "-"),
+            "line 1: unexpected end of input, expected a number"
+        );
+        assert_eq!(
+            error(signed::<i32>(), "-x"),
+            "line 1, column 2: unexpected character 'x'"
+        );
+        assert_eq!(
+            error(preceded(tag("x="), signed::<i8>()), "x=-129"),
+            "line 1, column 3: invalid i8 \"-129\""
+        );
+    }
+
+    #[test]
+    fn separated_lists() {
+        let list = || separated1(unsigned::<u32>(), tag(","));
+        assert_eq!(complete(&list(), "1,2,3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(complete(&list(), "1").unwrap(), vec![1]);
+        assert_eq!(
+            error(list(), ""),
+            "line 1: unexpected end of input, expected a number"
+        );
+        // After a separator there must be another item
+        assert_eq!(
+            error(list(), "1,2,"),
+            "line 1: unexpected end of input, expected a number"
+        );
+        assert_eq!(
+            error(list(), "1,2,x"),
+            "line 1, column 5: unexpected character 'x'"
+        );
+        assert_eq!(
+            error(list(), "1,2;3"),
+            "line 1, column 4: unexpected character ';'"
+        );
+        let empty = separated(unsigned::<u32>(), tag(","));
+        assert_eq!(complete(&empty, "").unwrap(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn optional_values() {
+        let sign = optional(tag("-"));
+        assert_eq!(
+            sign.parse(Input::new("x")).unwrap(),
+            (None, Input::new("x"))
+        );
+        assert_eq!(complete(&pair(sign, tag("x")), "-x").unwrap().0, Some("-"));
+        // A parser that fails after consuming something is not optional anymore
+        assert_eq!(
+            error(optional(pair(tag("-"), unsigned::<u32>())), "-x"),
+            "line 1, column 2: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn many_values() {
+        let terms = || many(preceded(tag("+"), unsigned::<u32>()));
+        assert_eq!(complete(&terms(), "+1+2+3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(complete(&terms(), "").unwrap(), Vec::<u32>::new());
+        assert_eq!(
+            error(terms(), "+1+2+x"),
+            "line 1, column 6: unexpected character 'x'"
+        );
+        // It stops when the parser does not consume anything
+        let (res, rest) = many(take_while(|c| c == 'a'))
+            .parse(Input::new("aab"))
+            .unwrap();
+        assert_eq!((res, rest.rest()), (vec!["aa"], "b"));
+    }
+
+    #[test]
+    fn either_reports_the_furthest_failure() {
+        let command = || {
+            either(
+                preceded(tag("move "), unsigned::<u32>()),
+                preceded(tag("mv "), unsigned::<u32>()),
+            )
+        };
+        assert_eq!(complete(&command(), "move 3").unwrap(), 3);
+        assert_eq!(complete(&command(), "mv 4").unwrap(), 4);
+        assert_eq!(
+            error(command(), "move x"),
+            "line 1, column 6: unexpected character 'x'"
+        );
+        assert_eq!(
+            error(command(), "mv x"),
+            "line 1, column 4: unexpected character 'x'"
+        );
+        assert_eq!(
+            error(command(), "mx 1"),
+            "line 1, column 2: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn blocks_of_lines() {
+        let numbers = || blocks(lines(unsigned::<u32>()));
+        assert_eq!(
+            complete(&numbers(), "1\n2\n\n3\n").unwrap(),
+            vec![vec![1, 2], vec![3]]
+        );
+        assert_eq!(
+            complete(&numbers(), "\n\n1\n\n\n2\n\n").unwrap(),
+            vec![vec![1], vec![2]]
+        );
+        assert_eq!(
+            error(numbers(), "1\n\n2\nx\n"),
+            "line 4, column 1: unexpected character 'x'"
+        );
+        let pairs = blocks(pair(line(tag("a")), line(tag("b"))));
+        assert_eq!(
+            error(pairs, "a\nb\n\na\nb\nc\n"),
+            "line 6, column 1: expected a blank line after the block"
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let test = || field("Test:", preceded(tag("divisible by "), unsigned::<u64>()));
+        assert_eq!(complete(&test(), "  Test: divisible by 23\n").unwrap(), 23);
+        assert_eq!(
+            error(test(), "  Tset: divisible by 23"),
+            "line 1, column 4: unexpected character 's'"
+        );
+        assert_eq!(
+            error(test(), "  Test: divisible bx 23"),
+            "line 1, column 20: unexpected character 'x'"
+        );
+        assert_eq!(
+            error(test(), "  Test: divisible by 23 x"),
+            "line 1, column 25: unexpected character 'x'"
+        );
+    }
+
+    #[test]
+    fn grids() {
+        let cell = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        let parser = pair(grid(cell), preceded(blank_lines(), tag("rest")));
+        let (map, _) = complete(&parser, "#.\n.#\n\nrest").unwrap();
+        assert_eq!((map.n_rows(), map.n_cols()), (2, 2));
+        assert_eq!(map.get(Point::new(1, 1)), Some(&true));
+        // Errors point to the cell within the whole text
+        let titled = || preceded(line(tag("map")), grid(cell));
+        assert_eq!(
+            error(titled(), "map\n#.\n.x\n"),
+            "line 3, column 2: unexpected character 'x'"
+        );
+        assert_eq!(
+            error(titled(), "map\n#.\n#\n"),
+            "line 3, column 1: expected 2 cells, found 1"
+        );
+        assert_eq!(
+            error(grid(cell), ""),
+            "line 1: unexpected end of input, expected a row of cells"
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Machine-readable results, so scripts and CI dashboards can consume the answers.
+//!
+//! Every answer is a [`Record`], which is printed as one JSON object per line (JSON lines):
+//!
+//! ```json
+//! {"day":1,"part":1,"answer":"24000","elapsed":0.000113}
+//! ```
+
+use crate::{solve, AocError};
+use serde::Serialize;
+use std::time::Instant;
+
+/// Answer of a part of a puzzle, together with the time it took to compute it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    /// Day of the puzzle.
+    pub day: usize,
+    /// Part of the puzzle.
+    pub part: usize,
+    /// Answer of the puzzle. It is always a string, as some answers are not numbers.
+    pub answer: String,
+    /// Time spent parsing the input and solving the part, in seconds. It is wall-clock time, so
+    /// it includes the time the part waited for other tasks of the thread pool (e.g., other days
+    /// solved at the same time by `run --all`).
+    pub elapsed: f64,
+}
+
+impl Record {
+    /// It solves a part of the puzzle of a given day and times it.
+    pub fn solve(day: usize, part: usize, input: &str) -> Result<Self, AocError> {
+        let start = Instant::now();
+        let answer = solve(day, part, input)?;
+        let elapsed = start.elapsed().as_secs_f64();
+        Ok(Self {
+            day,
+            part,
+            answer,
+            elapsed,
+        })
+    }
+
+    /// It returns the record as a single-line JSON object.
+    pub fn to_json(&self) -> String {
+        // Records only contain numbers and strings, so serialization cannot fail.
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
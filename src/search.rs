@@ -0,0 +1,329 @@
+//! Graph searches (BFS, Dijkstra, and A*) over any kind of node.
+//!
+//! Graphs are never built explicitly: searches only need a function that returns the
+//! neighbours of a node and, for weighted graphs, a function that returns the cost of
+//! moving from a node to one of its neighbours (or `None` if we cannot move there).
+//!
+//! Searches start from one or more sources, and stop as soon as they reach any node that
+//! satisfies the `is_target` predicate. If no node is a target, they explore every reachable node.
+//! The resulting [`Search`] contains the distances from the sources and the best predecessor
+//! of every reached node, so we can reconstruct the best paths.
+
+use num::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Result of a graph search.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    /// Best known distance from the sources to every reached node.
+    distances: HashMap<N, C>,
+    /// Best predecessor of every reached node (sources have no predecessor).
+    predecessors: HashMap<N, N>,
+    /// First target that we reached (if any).
+    target: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            target: None,
+        }
+    }
+
+    /// It returns the distance from the sources to every node reached by the search.
+    /// If the search stopped at a target, some distances may not be optimal yet.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// It returns the best predecessor of every node reached by the search.
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// It returns the distance from the sources to a node (if we reached it).
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// It returns the target that stopped the search (if any).
+    pub fn target(&self) -> Option<&N> {
+        self.target.as_ref()
+    }
+
+    /// It returns the distance from the sources to the target that stopped the search (if any).
+    pub fn target_distance(&self) -> Option<C> {
+        self.distance(self.target.as_ref()?)
+    }
+
+    /// It returns the best path from any source to a node (both included).
+    /// If we did not reach the node, it returns `None`.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut res = vec![node.clone()];
+        while let Some(prev) = self.predecessors.get(res.last()?) {
+            res.push(prev.clone());
+        }
+        res.reverse();
+        Some(res)
+    }
+}
+
+/// Breadth-first search for unweighted graphs. Distances are the number of steps.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_target: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut pending = VecDeque::new();
+    for source in sources {
+        if search.distances.insert(source.clone(), 0).is_none() {
+            pending.push_back(source);
+        }
+    }
+    while let Some(node) = pending.pop_front() {
+        if is_target(&node) {
+            search.target = Some(node);
+            break;
+        }
+        let distance = search.distances[&node] + 1;
+        for next in neighbours(&node) {
+            // In BFS, the first time that we reach a node is always the best one
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance);
+                search.predecessors.insert(next.clone(), node.clone());
+                pending.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm for weighted graphs. Costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    cost: impl FnMut(&N, &N) -> Option<C>,
+    is_target: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = N>,
+{
+    // Dijkstra is just A* without any hint
+    a_star(sources, neighbours, cost, |_| C::zero(), is_target)
+}
+
+/// Candidate node of Dijkstra and A*. The heap pops the candidate with the lowest estimate first.
+struct Candidate<N, C> {
+    /// Estimated cost of the best path through the node (cost + heuristic).
+    estimate: C,
+    /// Cost from the sources to the node.
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap, so we reverse the order. On ties, we prefer longer paths
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+/// A* algorithm for weighted graphs. Costs must not be negative.
+/// The `heuristic` estimates the cost from a node to the closest target.
+/// It must never overestimate it, or the resulting paths may not be the best ones.
+pub fn a_star<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut cost: impl FnMut(&N, &N) -> Option<C>,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_target: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut open_set = BinaryHeap::new();
+    for source in sources {
+        if search.distances.insert(source.clone(), C::zero()).is_none() {
+            open_set.push(Candidate {
+                estimate: heuristic(&source),
+                cost: C::zero(),
+                node: source,
+            });
+        }
+    }
+    while let Some(Candidate {
+        cost: current,
+        node,
+        ..
+    }) = open_set.pop()
+    {
+        // If we already found a better path to this node, this candidate is outdated
+        if search
+            .distances
+            .get(&node)
+            .is_some_and(|&best| best < current)
+        {
+            continue;
+        }
+        if is_target(&node) {
+            search.target = Some(node);
+            break;
+        }
+        for next in neighbours(&node) {
+            let Some(step) = cost(&node, &next) else {
+                continue; // We cannot move to this neighbour
+            };
+            let new_cost = current + step;
+            // If the new cost is less than the previous, we record this path
+            if search
+                .distances
+                .get(&next)
+                .is_none_or(|&prev| new_cost < prev)
+            {
+                search.distances.insert(next.clone(), new_cost);
+                search.predecessors.insert(next.clone(), node.clone());
+                open_set.push(Candidate {
+                    estimate: new_cost + heuristic(&next),
+                    cost: new_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Point;
+    use std::cell::RefCell;
+
+    /// It returns the neighbours of every node of a graph given as a list of directed edges.
+    fn graph(edges: &[(char, char, u32)]) -> impl Fn(&char) -> Vec<char> + '_ {
+        |&node| {
+            let next = edges.iter().filter(move |(from, _, _)| *from == node);
+            next.map(|&(_, to, _)| to).collect()
+        }
+    }
+
+    /// It returns the cost of an edge of a graph (if any).
+    fn cost(edges: &[(char, char, u32)]) -> impl Fn(&char, &char) -> Option<u32> + '_ {
+        |&from, &to| {
+            let mut edges = edges.iter().filter(move |e| (e.0, e.1) == (from, to));
+            edges.next().map(|&(_, _, cost)| cost)
+        }
+    }
+
+    #[test]
+    fn stale_candidates() {
+        // We reach b through the direct edge first, but the path through a is cheaper.
+        // The first candidate of b is still in the heap when we find the second one
+        let edges = [('s', 'b', 10), ('s', 'a', 1), ('a', 'b', 1), ('b', 't', 1)];
+        let expanded = RefCell::new(Vec::new());
+        let neighbours = |node: &char| {
+            expanded.borrow_mut().push(*node);
+            graph(&edges)(node)
+        };
+        let search = a_star(['s'], neighbours, cost(&edges), |_| 0, |&n| n == 't');
+        assert_eq!(search.target(), Some(&'t'));
+        assert_eq!(search.target_distance(), Some(3));
+        assert_eq!(search.path(&'t').unwrap(), ['s', 'a', 'b', 't']);
+        // The outdated candidate of b must not expand it again
+        assert_eq!(expanded.into_inner(), ['s', 'a', 'b']);
+    }
+
+    #[test]
+    fn several_sources_and_targets() {
+        let edges = [
+            ('a', 'c', 5),
+            ('b', 'c', 1),
+            ('c', 'x', 4),
+            ('c', 'y', 2),
+            ('b', 'x', 9),
+        ];
+        let is_target = |n: &char| matches!(n, 'x' | 'y');
+        let search = dijkstra(['a', 'b', 'a'], graph(&edges), cost(&edges), is_target);
+        // Every source starts at zero, and the search stops at the closest target
+        assert_eq!(search.distance(&'a'), Some(0));
+        assert_eq!(search.distance(&'b'), Some(0));
+        assert_eq!(search.target(), Some(&'y'));
+        assert_eq!(search.path(&'y').unwrap(), ['b', 'c', 'y']);
+        assert_eq!(search.path(&'a').unwrap(), ['a']);
+        // A source that is also a target stops the search right away
+        let search = dijkstra(['a', 'y'], graph(&edges), cost(&edges), is_target);
+        assert_eq!(
+            (search.target(), search.target_distance()),
+            (Some(&'y'), Some(0))
+        );
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        let edges = [('a', 'b', 1), ('b', 'a', 1), ('c', 'd', 1)];
+        let search = dijkstra(['a'], graph(&edges), cost(&edges), |&n| n == 'd');
+        assert_eq!(search.target(), None);
+        assert_eq!(search.path(&'d'), None);
+        assert_eq!(search.distances().len(), 2);
+        // Neighbours without cost are not reachable
+        let search = dijkstra(['a'], graph(&edges), |_, _| None::<u32>, |_| false);
+        assert_eq!(search.distances().len(), 1);
+    }
+
+    #[test]
+    fn a_star_matches_bfs() {
+        // A 10 × 10 room with a wall in the middle (with a hole at the bottom)
+        let open =
+            |p: &Point| (0..10).contains(&p.x) && (0..10).contains(&p.y) && (p.x != 5 || p.y == 9);
+        let neighbours = |p: &Point| p.neighbours4().into_iter().filter(open);
+        let target = Point::new(9, 0);
+        let manhattan = |p: &Point| ((p.x - target.x).abs() + (p.y - target.y).abs()) as usize;
+        let sources = [Point::new(0, 0), Point::new(2, 2)];
+        let expected = bfs(sources, neighbours, |&p| p == target);
+        let search = a_star(
+            sources,
+            neighbours,
+            |_, _| Some(1),
+            manhattan,
+            |&p| p == target,
+        );
+        assert_eq!(search.target_distance(), expected.target_distance());
+        assert_eq!(search.target_distance(), Some(7 + 7 + 9));
+        let path = search.path(&target).unwrap();
+        assert_eq!((path[0], path.len()), (Point::new(2, 2), 24));
+        assert!(path.windows(2).all(|w| w[0].neighbours4().contains(&w[1])));
+    }
+}
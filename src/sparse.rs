@@ -0,0 +1,365 @@
+//! Grids without borders, for simulations that may grow towards any direction.
+//!
+//! A [`SparseGrid`] is addressed by signed [`Point`]s and only stores the cells that we set.
+//! Every other cell is empty, no matter how far it is. Cells live in a [`Storage`] backend:
+//!
+//! - [`HashStorage`] (the default) keeps every cell in a hash map. It is the best choice when
+//!   cells are few and scattered (e.g., the elves of day 23).
+//! - [`ChunkStorage`] keeps cells in dense square chunks that are allocated on demand.
+//!   It is the best choice when cells are packed together (e.g., the sand of day 14).
+
+use crate::geom::Point;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Backend of a [`SparseGrid`]. Cells that were never set (or were removed) are empty.
+pub trait Storage<T>: Default {
+    /// It returns the cell in a given point (if any).
+    fn get(&self, point: Point) -> Option<&T>;
+
+    /// It returns a mutable reference to the cell in a given point (if any).
+    fn get_mut(&mut self, point: Point) -> Option<&mut T>;
+
+    /// It sets the cell in a given point and returns its previous value (if any).
+    fn insert(&mut self, point: Point, value: T) -> Option<T>;
+
+    /// It empties the cell in a given point and returns its previous value (if any).
+    fn remove(&mut self, point: Point) -> Option<T>;
+
+    /// It returns the number of non-empty cells.
+    fn len(&self) -> usize;
+
+    /// It returns true if every cell is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// It returns an iterator over the non-empty cells and their points (in no particular order).
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a;
+}
+
+/// Backend that keeps every cell in a hash map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashStorage<T>(HashMap<Point, T>);
+
+impl<T> Default for HashStorage<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T> Storage<T> for HashStorage<T> {
+    fn get(&self, point: Point) -> Option<&T> {
+        self.0.get(&point)
+    }
+
+    fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.0.get_mut(&point)
+    }
+
+    fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.0.insert(point, value)
+    }
+
+    fn remove(&mut self, point: Point) -> Option<T> {
+        self.0.remove(&point)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        self.0.iter().map(|(&p, c)| (p, c))
+    }
+}
+
+/// Length of the side of the chunks of a [`ChunkStorage`].
+pub const CHUNK_SIDE: i32 = 16;
+
+/// Backend that keeps cells in square chunks of [`CHUNK_SIDE`] × [`CHUNK_SIDE`] cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkStorage<T> {
+    /// Chunks indexed by their position (i.e., the point of any of their cells / CHUNK_SIDE).
+    chunks: HashMap<Point, Vec<Option<T>>>,
+    /// Number of non-empty cells.
+    len: usize,
+}
+
+impl<T> ChunkStorage<T> {
+    /// It returns the position of the chunk of a point and the index of the point in the chunk.
+    fn locate(point: Point) -> (Point, usize) {
+        let chunk = Point::new(
+            point.x.div_euclid(CHUNK_SIDE),
+            point.y.div_euclid(CHUNK_SIDE),
+        );
+        let (x, y) = (
+            point.x.rem_euclid(CHUNK_SIDE),
+            point.y.rem_euclid(CHUNK_SIDE),
+        );
+        (chunk, (y * CHUNK_SIDE + x) as usize)
+    }
+}
+
+impl<T> Default for ChunkStorage<T> {
+    fn default() -> Self {
+        Self {
+            chunks: HashMap::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> Storage<T> for ChunkStorage<T> {
+    fn get(&self, point: Point) -> Option<&T> {
+        let (chunk, i) = Self::locate(point);
+        self.chunks.get(&chunk)?[i].as_ref()
+    }
+
+    fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        let (chunk, i) = Self::locate(point);
+        self.chunks.get_mut(&chunk)?[i].as_mut()
+    }
+
+    fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        let (chunk, i) = Self::locate(point);
+        let chunk = self.chunks.entry(chunk).or_insert_with(|| {
+            let side = CHUNK_SIDE as usize;
+            (0..side * side).map(|_| None).collect()
+        });
+        let prev = chunk[i].replace(value);
+        if prev.is_none() {
+            self.len += 1;
+        }
+        prev
+    }
+
+    fn remove(&mut self, point: Point) -> Option<T> {
+        let (position, i) = Self::locate(point);
+        let chunk = self.chunks.get_mut(&position)?;
+        let prev = chunk[i].take();
+        if prev.is_some() {
+            self.len -= 1;
+            // We free chunks as soon as they are empty
+            if chunk.iter().all(Option::is_none) {
+                self.chunks.remove(&position);
+            }
+        }
+        prev
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (Point, &'a T)>
+    where
+        T: 'a,
+    {
+        self.chunks.iter().flat_map(|(&chunk, cells)| {
+            cells.iter().enumerate().filter_map(move |(i, cell)| {
+                let offset = Point::new(i as i32 % CHUNK_SIDE, i as i32 / CHUNK_SIDE);
+                cell.as_ref().map(|c| (chunk * CHUNK_SIDE + offset, c))
+            })
+        })
+    }
+}
+
+/// A grid without borders. It grows on demand as we set cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T, S = HashStorage<T>> {
+    storage: S,
+    _cells: std::marker::PhantomData<T>,
+}
+
+/// A [`SparseGrid`] that keeps its cells in dense chunks.
+pub type ChunkedGrid<T> = SparseGrid<T, ChunkStorage<T>>;
+
+impl<T, S: Storage<T>> Default for SparseGrid<T, S> {
+    fn default() -> Self {
+        Self {
+            storage: S::default(),
+            _cells: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, S: Storage<T>> SparseGrid<T, S> {
+    /// It creates an empty grid.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// It returns the cell in a given point (if any).
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.storage.get(point)
+    }
+
+    /// It returns a mutable reference to the cell in a given point (if any).
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.storage.get_mut(point)
+    }
+
+    /// It returns true if the cell in a given point is not empty.
+    pub fn contains(&self, point: Point) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// It sets the cell in a given point and returns its previous value (if any).
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.storage.insert(point, value)
+    }
+
+    /// It empties the cell in a given point and returns its previous value (if any).
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.storage.remove(point)
+    }
+
+    /// It returns the number of non-empty cells.
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// It returns true if every cell is empty.
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    /// It returns an iterator over the non-empty cells and their points (in no particular order).
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.storage.iter()
+    }
+
+    /// It returns the upper-left and lower-right corners of the smallest rectangle
+    /// that contains every non-empty cell. If the grid is empty, it returns `None`.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.iter().map(|(p, _)| p).fold(None, |bounds, p| {
+            let (min, max) = bounds.unwrap_or((p, p));
+            Some((
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            ))
+        })
+    }
+
+    /// It draws the bounding box of the grid with one symbol per cell and one line per row.
+    /// `symbol` receives `None` for empty cells.
+    pub fn render<R: Display>(&self, mut symbol: impl FnMut(Option<&T>) -> R) -> String {
+        let mut res = String::new();
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    res += &symbol(self.get(Point::new(x, y))).to_string();
+                }
+                res.push('\n');
+            }
+        }
+        res
+    }
+}
+
+impl<T, S: Storage<T>> FromIterator<(Point, T)> for SparseGrid<T, S> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        for (point, value) in iter {
+            res.insert(point, value);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points around the origin and the edges of the chunks, with negative coordinates too.
+    const POINTS: [(i32, i32); 8] = [
+        (0, 0),
+        (-1, 0),
+        (0, -1),
+        (15, 15),
+        (16, -16),
+        (-16, -17),
+        (-17, 33),
+        (i32::MIN, i32::MAX),
+    ];
+
+    /// It checks that a backend keeps every cell in its own place.
+    fn check_storage<S: Storage<usize>>() {
+        let mut grid = SparseGrid::<usize, S>::new();
+        for (i, &(x, y)) in POINTS.iter().enumerate() {
+            assert_eq!(grid.insert(Point::new(x, y), i), None);
+        }
+        assert_eq!(grid.len(), POINTS.len());
+        for (i, &(x, y)) in POINTS.iter().enumerate() {
+            assert_eq!(grid.get(Point::new(x, y)), Some(&i), "{:?}", (x, y));
+        }
+        let mut cells: Vec<_> = grid.iter().map(|(p, &i)| (i, (p.x, p.y))).collect();
+        cells.sort();
+        assert!(cells.iter().map(|&(_, p)| p).eq(POINTS));
+        assert!(!grid.contains(Point::new(1, 0)));
+        assert!(!grid.contains(Point::new(-2, 0)));
+        *grid.get_mut(Point::new(-1, 0)).unwrap() += 10;
+        assert_eq!(grid.insert(Point::new(-1, 0), 0), Some(11));
+        assert_eq!(grid.remove(Point::new(-16, -17)), Some(5));
+        assert_eq!(grid.remove(Point::new(-16, -17)), None);
+        assert_eq!(grid.len(), POINTS.len() - 1);
+    }
+
+    #[test]
+    fn hash_storage() {
+        check_storage::<HashStorage<usize>>();
+    }
+
+    #[test]
+    fn chunk_storage() {
+        check_storage::<ChunkStorage<usize>>();
+    }
+
+    #[test]
+    fn negative_chunks() {
+        let locate = |x, y| ChunkStorage::<()>::locate(Point::new(x, y));
+        assert_eq!(locate(0, 0), (Point::new(0, 0), 0));
+        assert_eq!(locate(15, 15), (Point::new(0, 0), 255));
+        assert_eq!(locate(-1, 0), (Point::new(-1, 0), 15));
+        assert_eq!(locate(-16, -1), (Point::new(-1, -1), 240));
+        assert_eq!(locate(-17, -17), (Point::new(-2, -2), 255));
+    }
+
+    #[test]
+    fn chunks_are_freed() {
+        let mut storage = ChunkStorage::default();
+        storage.insert(Point::new(-1, -1), 'a');
+        storage.insert(Point::new(-16, -16), 'b');
+        storage.insert(Point::new(0, 0), 'c');
+        assert_eq!(storage.chunks.len(), 2);
+        // Removing empty cells (even of missing chunks) changes nothing
+        assert_eq!(storage.remove(Point::new(-2, -2)), None);
+        assert_eq!(storage.remove(Point::new(100, 100)), None);
+        assert_eq!((storage.len(), storage.chunks.len()), (3, 2));
+        assert_eq!(storage.remove(Point::new(-1, -1)), Some('a'));
+        assert_eq!((storage.len(), storage.chunks.len()), (2, 2));
+        assert_eq!(storage.remove(Point::new(-16, -16)), Some('b'));
+        assert_eq!((storage.len(), storage.chunks.len()), (1, 1));
+        assert_eq!(storage.remove(Point::new(0, 0)), Some('c'));
+        assert!(storage.is_empty());
+        assert!(storage.chunks.is_empty());
+        assert_eq!(storage, ChunkStorage::default());
+    }
+
+    #[test]
+    fn bounds_and_render() {
+        let grid: ChunkedGrid<char> = [(Point::new(-2, -1), '#'), (Point::new(1, 0), 'o')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.bounds(), Some((Point::new(-2, -1), Point::new(1, 0))));
+        assert_eq!(grid.render(|c| c.copied().unwrap_or('.')), "#...\n...o\n");
+        assert_eq!(ChunkedGrid::<char>::new().bounds(), None);
+        assert_eq!(ChunkedGrid::<char>::new().render(|_| '.'), "");
+    }
+}
@@ -0,0 +1,487 @@
+//! Local store of puzzle inputs, so several people can keep their own inputs side by side.
+//!
+//! Every input is identified by a [`Key`]: the profile of its owner, the year, and the day.
+//! Inputs live in `<root>/<profile>/<year>/NN.txt`, and every directory has a `checksums.toml`
+//! file with the SHA-256 of each input:
+//!
+//! ```toml
+//! day01 = "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+//! ```
+//!
+//! Inputs are checked against their checksums whenever we read them, so we notice when an input
+//! was truncated or edited by mistake. The directory may also have an `answers.toml` file with
+//! the known answers of the profile (see [`crate::answers`]).
+
+use crate::N_DAYS;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default location of the store.
+pub const STORE_PATH: &str = "data/inputs";
+
+/// Year of the puzzles of this repo.
+pub const YEAR: u16 = 2022;
+
+/// Name of the file with the checksums of the inputs of a directory.
+const CHECKSUMS_FILE: &str = "checksums.toml";
+
+/// Name of the file with the answers of a profile.
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// Identifier of an input in the store.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Key {
+    /// Owner of the input (e.g., `alice`).
+    pub profile: String,
+    pub year: u16,
+    pub day: usize,
+}
+
+impl Key {
+    /// It creates the key of an input of this year.
+    pub fn new(profile: &str, day: usize) -> Self {
+        Self {
+            profile: profile.to_string(),
+            year: YEAR,
+            day,
+        }
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/day {}", self.profile, self.year, self.day)
+    }
+}
+
+/// Errors of the store.
+#[derive(Debug)]
+pub enum StoreError {
+    /// A file or directory could not be read or written.
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    /// Profiles must be non-empty names with letters, digits, `-`, or `_`.
+    InvalidProfile(String),
+    /// Days go from 1 to 25.
+    InvalidDay(usize),
+    /// There is no input for the key.
+    Missing(Key),
+    /// The checksums file is malformed.
+    Checksums { path: PathBuf, msg: String },
+    /// The content of the input does not match its checksum.
+    Corrupted {
+        key: Key,
+        expected: String,
+        found: String,
+    },
+    /// Two files of an imported directory are inputs of the same day.
+    Duplicate { day: usize, paths: [PathBuf; 2] },
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            Self::InvalidProfile(profile) => write!(f, "invalid profile {:?}", profile),
+            Self::InvalidDay(day) => write!(f, "invalid day {}", day),
+            Self::Missing(key) => write!(f, "there is no input for {}", key),
+            Self::Checksums { path, msg } => write!(f, "{}: {}", path.display(), msg),
+            Self::Corrupted {
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "the input of {} was modified (expected checksum {}, found {})",
+                key, expected, found
+            ),
+            Self::Duplicate { day, paths } => write!(
+                f,
+                "{} and {} are both inputs of day {}",
+                paths[0].display(),
+                paths[1].display(),
+                day
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// It returns the SHA-256 of a text in hexadecimal.
+pub fn checksum(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// It returns the day of an input file from its name (if any).
+/// We accept names like `01_input.txt`, `day01.txt`, `input-1.txt`, or `1.txt`.
+/// Samples (e.g., `01_sample.txt`) are not inputs.
+pub fn day_of_file(name: &str) -> Option<usize> {
+    let re = Regex::new(r"^(?i)(?:day|input)?[_-]?(\d{1,2})(?:[_-]input)?\.txt$").unwrap();
+    let day = re.captures(name)?[1].parse().ok()?;
+    (1..=N_DAYS).contains(&day).then_some(day)
+}
+
+/// State of an input according to its checksum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// The input matches its checksum.
+    Valid,
+    /// The input does not match its checksum.
+    Corrupted { expected: String, found: String },
+    /// The input has no checksum (e.g., it was copied by hand).
+    Untracked,
+    /// There is a checksum, but the input is gone.
+    Missing,
+}
+
+/// Store of puzzle inputs in the local disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Store {
+    root: PathBuf,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self::new(STORE_PATH)
+    }
+}
+
+impl Store {
+    /// It creates a store in a given directory. The directory is created when needed.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Root directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// It returns the directory with the inputs of a profile and year.
+    pub fn dir(&self, profile: &str, year: u16) -> Result<PathBuf, StoreError> {
+        let valid = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+        match !profile.is_empty() && profile.chars().all(valid) {
+            true => Ok(self.root.join(profile).join(year.to_string())),
+            false => Err(StoreError::InvalidProfile(profile.to_string())),
+        }
+    }
+
+    /// It returns the path of an input (it may not exist).
+    pub fn path(&self, key: &Key) -> Result<PathBuf, StoreError> {
+        if !(1..=N_DAYS).contains(&key.day) {
+            return Err(StoreError::InvalidDay(key.day));
+        }
+        Ok(self
+            .dir(&key.profile, key.year)?
+            .join(format!("{:02}.txt", key.day)))
+    }
+
+    /// It returns the path of the answers of a profile (it may not exist).
+    pub fn answers_path(&self, profile: &str, year: u16) -> Result<PathBuf, StoreError> {
+        Ok(self.dir(profile, year)?.join(ANSWERS_FILE))
+    }
+
+    /// It returns the profiles of the store, sorted by name.
+    pub fn profiles(&self) -> Result<Vec<String>, StoreError> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+        let mut res = Vec::new();
+        for entry in read_dir(&self.root)? {
+            if entry.path().is_dir() {
+                res.push(entry.file_name().to_string_lossy().into_owned());
+            }
+        }
+        res.sort();
+        Ok(res)
+    }
+
+    /// It returns the checksums of a profile and year, indexed by day.
+    fn checksums(&self, profile: &str, year: u16) -> Result<BTreeMap<usize, String>, StoreError> {
+        let path = self.dir(profile, year)?.join(CHECKSUMS_FILE);
+        if !path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        let content = read(&path)?;
+        let error = |msg: String| StoreError::Checksums {
+            path: path.clone(),
+            msg,
+        };
+        let raw: BTreeMap<String, String> =
+            toml::from_str(&content).map_err(|e| error(e.message().to_string()))?;
+        let mut res = BTreeMap::new();
+        for (key, value) in raw {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse().ok())
+                .filter(|d| (1..=N_DAYS).contains(d))
+                .ok_or_else(|| error(format!("unknown key {:?} (expected dayNN)", key)))?;
+            res.insert(day, value);
+        }
+        Ok(res)
+    }
+
+    /// It saves the checksums of a profile and year.
+    fn save_checksums(
+        &self,
+        profile: &str,
+        year: u16,
+        checksums: &BTreeMap<usize, String>,
+    ) -> Result<(), StoreError> {
+        let path = self.dir(profile, year)?.join(CHECKSUMS_FILE);
+        let content: String = checksums
+            .iter()
+            .map(|(day, checksum)| format!("day{:02} = \"{}\"\n", day, checksum))
+            .collect();
+        write(&path, &content)
+    }
+
+    /// It adds (or replaces) an input and records its checksum. It returns the checksum.
+    pub fn insert(&self, key: &Key, content: &str) -> Result<String, StoreError> {
+        let path = self.path(key)?;
+        let dir = self.dir(&key.profile, key.year)?;
+        fs::create_dir_all(&dir).map_err(|error| StoreError::Io { path: dir, error })?;
+        write(&path, content)?;
+        let mut checksums = self.checksums(&key.profile, key.year)?;
+        let res = checksum(content);
+        checksums.insert(key.day, res.clone());
+        self.save_checksums(&key.profile, key.year, &checksums)?;
+        Ok(res)
+    }
+
+    /// It reads an input and checks that it matches its checksum.
+    /// Inputs without checksum are returned as they are.
+    pub fn get(&self, key: &Key) -> Result<String, StoreError> {
+        let path = self.path(key)?;
+        if !path.exists() {
+            return Err(StoreError::Missing(key.clone()));
+        }
+        let content = read(&path)?;
+        if let Some(expected) = self.checksums(&key.profile, key.year)?.remove(&key.day) {
+            let found = checksum(&content);
+            if found != expected {
+                return Err(StoreError::Corrupted {
+                    key: key.clone(),
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(content)
+    }
+
+    /// It checks every input of a profile and year. It returns the state of every day
+    /// with an input or a checksum.
+    pub fn check(&self, profile: &str, year: u16) -> Result<Vec<(usize, Check)>, StoreError> {
+        let checksums = self.checksums(profile, year)?;
+        let mut res = Vec::new();
+        for day in 1..=N_DAYS {
+            let key = Key {
+                profile: profile.to_string(),
+                year,
+                day,
+            };
+            let path = self.path(&key)?;
+            let check = match (path.exists(), checksums.get(&day)) {
+                (false, None) => continue,
+                (false, Some(_)) => Check::Missing,
+                (true, None) => Check::Untracked,
+                (true, Some(expected)) => match checksum(&read(&path)?) {
+                    found if &found == expected => Check::Valid,
+                    found => Check::Corrupted {
+                        expected: expected.clone(),
+                        found,
+                    },
+                },
+            };
+            res.push((day, check));
+        }
+        Ok(res)
+    }
+
+    /// It imports every input file of a directory (see [`day_of_file`]) for a profile and year.
+    /// It returns the imported days, sorted. If two files are inputs of the same day, it fails
+    /// before importing anything.
+    pub fn import(&self, profile: &str, year: u16, dir: &Path) -> Result<Vec<usize>, StoreError> {
+        let mut files = BTreeMap::new();
+        for entry in read_dir(dir)? {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if let Some(day) = day_of_file(&name).filter(|_| entry.path().is_file()) {
+                if let Some(other) = files.insert(day, entry.path()) {
+                    // Directories are not listed in any particular order, so we sort the paths
+                    let mut paths = [other, entry.path()];
+                    paths.sort();
+                    return Err(StoreError::Duplicate { day, paths });
+                }
+            }
+        }
+        for (&day, path) in files.iter() {
+            let key = Key {
+                profile: profile.to_string(),
+                year,
+                day,
+            };
+            self.insert(&key, &read(path)?)?;
+        }
+        Ok(files.into_keys().collect())
+    }
+}
+
+/// It reads a file, keeping its path in the error.
+fn read(path: &Path) -> Result<String, StoreError> {
+    fs::read_to_string(path).map_err(|error| StoreError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// It writes a file, keeping its path in the error.
+fn write(path: &Path, content: &str) -> Result<(), StoreError> {
+    fs::write(path, content).map_err(|error| StoreError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// It lists a directory, keeping its path in the error.
+fn read_dir(dir: &Path) -> Result<Vec<fs::DirEntry>, StoreError> {
+    let error = |error| StoreError::Io {
+        path: dir.to_path_buf(),
+        error,
+    };
+    fs::read_dir(dir)
+        .map_err(error)?
+        .collect::<Result<_, _>>()
+        .map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Temporary directory for the store of a test. It is removed when it is dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(test: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("aoc-store-{}-{}", test, std::process::id()));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn insert_and_get() {
+        let tmp = TempDir::new("insert");
+        let store = Store::new(tmp.0.join("inputs"));
+        let key = Key::new("alice", 7);
+        let expected = checksum("$ cd /\n");
+        assert_eq!(store.insert(&key, "$ cd /\n").unwrap(), expected);
+        assert_eq!(store.get(&key).unwrap(), "$ cd /\n");
+        assert_eq!(store.profiles().unwrap(), ["alice"]);
+        assert_eq!(store.check("alice", YEAR).unwrap(), [(7, Check::Valid)]);
+        let checksums = read(&store.dir("alice", YEAR).unwrap().join(CHECKSUMS_FILE)).unwrap();
+        assert_eq!(checksums, format!("day07 = \"{}\"\n", expected));
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let tmp = TempDir::new("mismatch");
+        let store = Store::new(&tmp.0);
+        let key = Key::new("alice", 1);
+        let expected = store.insert(&key, "1000\n2000\n").unwrap();
+        // Someone truncates the input by mistake
+        fs::write(store.path(&key).unwrap(), "1000\n").unwrap();
+        let found = checksum("1000\n");
+        match store.get(&key) {
+            Err(StoreError::Corrupted {
+                key: k,
+                expected: e,
+                found: f,
+            }) => assert_eq!((k, e, f), (key.clone(), expected.clone(), found.clone())),
+            res => panic!("unexpected result {:?}", res),
+        }
+        let corrupted = Check::Corrupted { expected, found };
+        assert_eq!(store.check("alice", YEAR).unwrap(), [(1, corrupted)]);
+        // The input is gone, but its checksum is still there
+        fs::remove_file(store.path(&key).unwrap()).unwrap();
+        assert_eq!(store.check("alice", YEAR).unwrap(), [(1, Check::Missing)]);
+    }
+
+    #[test]
+    fn missing_profile() {
+        let tmp = TempDir::new("missing");
+        let store = Store::new(tmp.0.join("inputs"));
+        assert!(store.profiles().unwrap().is_empty());
+        assert!(store.check("bob", YEAR).unwrap().is_empty());
+        let key = Key::new("bob", 3);
+        assert!(matches!(store.get(&key), Err(StoreError::Missing(k)) if k == key));
+        assert!(matches!(
+            store.get(&Key::new("../bob", 3)),
+            Err(StoreError::InvalidProfile(_))
+        ));
+        assert!(matches!(
+            store.get(&Key::new("bob", 26)),
+            Err(StoreError::InvalidDay(26))
+        ));
+    }
+
+    #[test]
+    fn import() {
+        let tmp = TempDir::new("import");
+        let dir = tmp.0.join("downloads");
+        fs::create_dir_all(dir.join("day03.txt")).unwrap();
+        for (name, content) in [
+            ("01_input.txt", "1000\n"),
+            ("day02.txt", "A Y\n"),
+            ("04_sample.txt", "2-4,6-8\n"),
+            ("notes.txt", "nothing to see here\n"),
+        ] {
+            fs::write(dir.join(name), content).unwrap();
+        }
+        let store = Store::new(tmp.0.join("inputs"));
+        assert_eq!(store.import("alice", YEAR, &dir).unwrap(), [1, 2]);
+        assert_eq!(store.get(&Key::new("alice", 1)).unwrap(), "1000\n");
+        assert_eq!(store.get(&Key::new("alice", 2)).unwrap(), "A Y\n");
+        let checks = store.check("alice", YEAR).unwrap();
+        assert_eq!(checks, [(1, Check::Valid), (2, Check::Valid)]);
+    }
+
+    #[test]
+    fn import_duplicate_days() {
+        let tmp = TempDir::new("duplicate");
+        let dir = tmp.0.join("downloads");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01_input.txt"), "1000\n").unwrap();
+        fs::write(dir.join("02_input.txt"), "A Y\n").unwrap();
+        fs::write(dir.join("day01.txt"), "2000\n").unwrap();
+        let store = Store::new(tmp.0.join("inputs"));
+        match store.import("alice", YEAR, &dir) {
+            Err(StoreError::Duplicate { day, paths }) => {
+                assert_eq!(day, 1);
+                assert_eq!(paths, [dir.join("01_input.txt"), dir.join("day01.txt")]);
+            }
+            res => panic!("unexpected result {:?}", res),
+        }
+        // Nothing was imported
+        assert!(store.profiles().unwrap().is_empty());
+    }
+}
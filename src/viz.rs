@@ -0,0 +1,221 @@
+//! Step-by-step animations of the simulations, so we can watch them in the terminal.
+//!
+//! Simulations that implement [`Visualize`] can be played with a [`Player`]. The player redraws
+//! the terminal with ANSI escape codes after every step, and reads [`Control`]s (pause, step,
+//! speed up...) from a channel. [`stdin_controls`] feeds that channel with the lines typed by the user.
+//! Animations are also [`Scene`]s, so every frame can be exported as a picture (see [`crate::export`]).
+
+use crate::days::{Day08, Day09, Day12, Day14, Day17, Day22, Day23, Day24};
+use crate::export::Render;
+use crate::{AocError, Solution, N_PARTS};
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// Simulations that can be watched step by step.
+pub trait Visualize {
+    /// It advances the simulation one step. It returns false if the simulation is already over.
+    fn step(&mut self) -> bool;
+
+    /// It draws the current state of the simulation.
+    fn frame(&self) -> String;
+
+    /// It returns a short summary of the current state (e.g., the number of rounds).
+    fn status(&self) -> String {
+        String::new()
+    }
+}
+
+/// Simulations that can be watched in the terminal and exported as pictures.
+pub trait Scene: Visualize + Render {}
+
+impl<T: Visualize + Render> Scene for T {}
+
+/// Days with an animation.
+pub const ANIMATED_DAYS: [usize; 8] = [8, 9, 12, 14, 17, 22, 23, 24];
+
+/// It returns the animation of the given `part` of the puzzle of a given `day` from its `input`.
+/// If the day has no animation (see [`ANIMATED_DAYS`]), it returns `None`.
+pub fn animation(day: usize, part: usize, input: &str) -> Result<Option<Box<dyn Scene>>, AocError> {
+    if part == 0 || part > N_PARTS {
+        return Err(AocError::UnknownPuzzle { day, part });
+    }
+    let res = match day {
+        8 => Day08::parse(input)?.animation(part),
+        9 => Day09::parse(input)?.animation(part),
+        12 => Day12::parse(input)?.animation(part),
+        14 => Day14::parse(input)?.animation(part),
+        17 => Day17::parse(input)?.animation(part),
+        22 => Day22::parse(input)?.animation(part)?,
+        23 => Day23::parse(input)?.animation(part),
+        24 => Day24::parse(input)?.animation(part),
+        _ => return Ok(None),
+    };
+    Ok(Some(res))
+}
+
+/// Commands that the user can send to a [`Player`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// It pauses or resumes the animation.
+    Toggle,
+    /// It pauses the animation (if needed) and advances one step.
+    Step,
+    /// It doubles the frame rate.
+    Faster,
+    /// It halves the frame rate.
+    Slower,
+    /// It stops the animation.
+    Quit,
+}
+
+impl Control {
+    /// It returns the control of a line typed by the user. An empty line toggles the pause.
+    pub fn from_line(line: &str) -> Option<Self> {
+        match line.trim() {
+            "" | "p" => Some(Self::Toggle),
+            "s" | "n" => Some(Self::Step),
+            "+" => Some(Self::Faster),
+            "-" => Some(Self::Slower),
+            "q" => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// It reads controls from stdin in a background thread (one control per line).
+/// The channel is closed when stdin is closed.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if let Some(control) = Control::from_line(&line) {
+                if tx.send(control).is_err() {
+                    break; // Nobody is listening anymore
+                }
+            }
+        }
+    });
+    rx
+}
+
+/// Fastest frame rate of the player.
+const MAX_FPS: f64 = 1000.;
+
+/// Slowest frame rate of the player.
+const MIN_FPS: f64 = 0.25;
+
+/// It plays animations in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Player {
+    /// Steps per second.
+    fps: f64,
+    /// If true, the animation only advances when the user asks for a step.
+    paused: bool,
+}
+
+impl Player {
+    /// It creates a player with a given frame rate (in steps per second).
+    pub fn new(fps: f64) -> Self {
+        Self {
+            fps: fps.clamp(MIN_FPS, MAX_FPS),
+            paused: false,
+        }
+    }
+
+    /// It makes the player start paused (or not).
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// It plays an animation until it is over or the user quits. It returns the number of steps.
+    pub fn play(
+        &mut self,
+        animation: &mut dyn Visualize,
+        controls: &Receiver<Control>,
+        out: &mut impl Write,
+    ) -> io::Result<usize> {
+        // We clear the screen and hide the cursor while playing
+        write!(out, "\x1b[2J\x1b[?25l")?;
+        let mut steps = 0;
+        let mut over = false;
+        self.draw(animation, steps, over, out)?;
+        while !over {
+            let period = Duration::from_secs_f64(1. / self.fps);
+            let control = match self.paused {
+                // If stdin is closed, nobody can resume the animation, so we do it ourselves
+                true => controls.recv().ok().or(Some(Control::Toggle)),
+                false => match controls.recv_timeout(period) {
+                    Ok(control) => Some(control),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        thread::sleep(period);
+                        None
+                    }
+                },
+            };
+            let advance = match control {
+                None => true,
+                Some(Control::Toggle) => {
+                    self.paused = !self.paused;
+                    false
+                }
+                Some(Control::Step) => {
+                    self.paused = true;
+                    true
+                }
+                Some(Control::Faster) => {
+                    self.fps = (self.fps * 2.).min(MAX_FPS);
+                    false
+                }
+                Some(Control::Slower) => {
+                    self.fps = (self.fps / 2.).max(MIN_FPS);
+                    false
+                }
+                Some(Control::Quit) => break,
+            };
+            if advance {
+                match animation.step() {
+                    true => steps += 1,
+                    false => over = true,
+                }
+            }
+            self.draw(animation, steps, over, out)?;
+        }
+        write!(out, "\x1b[?25h")?;
+        out.flush()?;
+        Ok(steps)
+    }
+
+    /// It redraws the terminal with the current frame and a status line.
+    fn draw(
+        &self,
+        animation: &dyn Visualize,
+        steps: usize,
+        over: bool,
+        out: &mut impl Write,
+    ) -> io::Result<()> {
+        // We go back to the upper-left corner and overwrite the previous frame line by line
+        let mut screen = String::from("\x1b[H");
+        for line in animation.frame().lines() {
+            screen += line;
+            screen += "\x1b[K\n";
+        }
+        let state = match (over, self.paused) {
+            (true, _) => "over".to_string(),
+            (false, true) => "paused".to_string(),
+            (false, false) => format!("{} fps", self.fps),
+        };
+        screen += &format!("step {} ({}) {}\x1b[K\n", steps, state, animation.status());
+        if !over {
+            screen += "[enter] pause/resume, [s] step, [+/-] speed, [q] quit\x1b[K\n";
+        }
+        // Finally, we clear whatever remains from the previous frame
+        screen += "\x1b[J";
+        out.write_all(screen.as_bytes())?;
+        out.flush()
+    }
+}
@@ -0,0 +1,96 @@
+//! Differential tests: the fast solvers of some days must agree with their slow but obviously
+//! correct references (see the `Reference` types of days 15, 17, and 19) on small random inputs.
+
+use aoc_2022::days::{day15, day17, day19, Day15, Day17, Day19};
+use aoc_2022::generate::{generate, Rng};
+use aoc_2022::Solution;
+
+/// Number of random inputs of every test.
+const N_INPUTS: u64 = 100;
+
+/// It returns the answers of both parts of a solution (errors become `None`).
+fn answers<S: Solution>(solution: &S) -> [Option<String>; 2] {
+    [
+        solution.part1().ok().map(|a| a.to_string()),
+        solution.part2().ok().map(|a| a.to_string()),
+    ]
+}
+
+/// Row and upper limit of the search space of day 15 with small inputs (the ones of the example).
+const DAY15_PARAMS: (i32, i32) = (10, 20);
+
+/// It writes random sensors within the search space of the example of day 15 (21x21 positions).
+/// Inputs may have many gaps, or none.
+fn small_sensors(seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut res = String::new();
+    for _ in 0..rng.range(1..=12) {
+        let (x, y) = (rng.range(0..=20), rng.range(0..=20));
+        let (dx, dy) = (rng.range(-8..=8), rng.range(-8..=8));
+        res.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            x,
+            y,
+            x + dx,
+            y + dy
+        ));
+    }
+    res
+}
+
+#[test]
+fn day15_matches_reference() {
+    for seed in 0..N_INPUTS {
+        let input = small_sensors(seed);
+        let (row, max_val) = DAY15_PARAMS;
+        let (fast, slow) = (
+            answers(&Day15::with_params(&input, row, max_val).unwrap()),
+            answers(&day15::Reference::with_params(&input, row, max_val).unwrap()),
+        );
+        assert_eq!(fast, slow, "seed {}\n{}", seed, input);
+    }
+}
+
+#[test]
+fn day17_matches_reference() {
+    for seed in 0..N_INPUTS {
+        // Short patterns have short cycles, so we extrapolate most of the tower
+        let size = 1 + seed as usize % 50;
+        let input = generate(17, seed, Some(size)).unwrap();
+        let (fast, slow) = (
+            Day17::parse(&input).unwrap(),
+            day17::Reference::parse(&input).unwrap(),
+        );
+        for n_rocks in [0, 1, 7, 100, 1001, 2022] {
+            assert_eq!(
+                fast.height(n_rocks),
+                slow.height(n_rocks),
+                "{} rocks, seed {}\n{}",
+                n_rocks,
+                seed,
+                input
+            );
+        }
+    }
+}
+
+#[test]
+fn day19_matches_reference() {
+    for seed in 0..N_INPUTS / 10 {
+        let input = generate(19, seed, Some(3)).unwrap();
+        let (fast, slow) = (
+            Day19::parse(&input).unwrap(),
+            day19::Reference::parse(&input).unwrap(),
+        );
+        for minutes in [1, 12, 16] {
+            assert_eq!(
+                fast.geodes(minutes),
+                slow.geodes(minutes),
+                "{} minutes, seed {}\n{}",
+                minutes,
+                seed,
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Stress tests: every day must solve the random inputs of its generator.
+//! Inputs are small, so the tests are fast even without optimizations.
+
+use aoc_2022::generate::generate;
+use aoc_2022::{solve, N_DAYS};
+
+/// Seeds of the random inputs of every day.
+const SEEDS: [u64; 3] = [0, 1, 2022];
+
+/// It solves some parts of small random inputs of a given day.
+fn check_parts(day: usize, parts: &[usize], size: usize) {
+    for seed in SEEDS {
+        let input = generate(day, seed, Some(size)).unwrap();
+        for &part in parts {
+            if let Err(e) = solve(day, part, &input) {
+                panic!(
+                    "day {} part {} (seed {}): {}\n{}",
+                    day, part, seed, e, input
+                );
+            }
+        }
+    }
+}
+
+/// It solves both parts of small random inputs of a given day.
+fn check(day: usize, size: usize) {
+    check_parts(day, &[1, 2], size);
+}
+
+/// It creates a test for every day with the size of its random inputs.
+macro_rules! generated {
+    ($($day:literal => $name:ident: $size:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day, $size);
+            }
+        )*
+    };
+}
+
+generated! {
+    1 => day01: 20;
+    2 => day02: 20;
+    3 => day03: 10;
+    4 => day04: 20;
+    5 => day05: 20;
+    6 => day06: 100;
+    7 => day07: 20;
+    8 => day08: 10;
+    9 => day09: 50;
+    10 => day10: 20;
+    11 => day11: 4;
+    12 => day12: 30;
+    13 => day13: 20;
+    14 => day14: 10;
+    16 => day16: 10;
+    17 => day17: 40;
+    18 => day18: 100;
+    20 => day20: 50;
+    21 => day21: 50;
+    22 => day22: 4;
+    23 => day23: 10;
+    24 => day24: 10;
+    25 => day25: 20;
+}
+
+// The search space of the fifteenth day is always the one of the real puzzle, and the second part
+// of the nineteenth day is too slow without optimizations.
+// Run them with `cargo test --release -- --ignored`.
+
+#[test]
+#[ignore = "it takes about a minute in release mode"]
+fn day15() {
+    check(15, 4);
+}
+
+#[test]
+fn day19_part1() {
+    check_parts(19, &[1], 2);
+}
+
+#[test]
+#[ignore = "it takes a few seconds in release mode"]
+fn day19_part2() {
+    check_parts(19, &[2], 2);
+}
+
+#[test]
+fn smallest_sizes() {
+    // The fifteenth day is slow with any input, and the nineteenth only in its second part
+    for day in (1..=N_DAYS).filter(|&day| day != 15) {
+        let parts: &[usize] = match day {
+            19 => &[1],
+            _ => &[1, 2],
+        };
+        for size in 0..=2 {
+            check_parts(day, parts, size);
+        }
+    }
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in 1..=N_DAYS {
+        let input = generate(day, 42, None).unwrap();
+        assert_eq!(input, generate(day, 42, None).unwrap(), "day {}", day);
+        assert_ne!(input, generate(day, 43, None).unwrap(), "day {}", day);
+    }
+}
@@ -0,0 +1,364 @@
+//! Property tests: the primitives of some days must satisfy their algebraic properties
+//! (round-trips, total orders, symmetries...) with thousands of random inputs.
+
+use aoc_2022::days::day01::{self, top_elves, Inventory};
+use aoc_2022::days::day02::{Day02, Decoding, Outcome, Rules, Symbol, CLASSIC_RULES};
+use aoc_2022::days::day03::{get_priority, Day03, ItemSet};
+use aoc_2022::days::day04::{fully_overlaps, overlaps, ElfAreas};
+use aoc_2022::days::day13::Element;
+use aoc_2022::days::day25::{dec_to_snafu, read_input};
+use aoc_2022::{solve, AocError, Solution};
+use proptest::prelude::*;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeSet;
+
+/// Number of random cases of every property.
+const CASES: u32 = 4096;
+
+/// It generates SNAFU numbers in their canonical form (i.e., without leading zeros).
+/// They have at most 27 digits, so they always fit in an `i64`.
+fn snafu() -> impl Strategy<Value = String> {
+    prop_oneof!["0", "[12][=\\-012]{0,26}"]
+}
+
+/// It generates packets of day 13 with up to 4 levels of nested lists.
+fn element() -> impl Strategy<Value = Element> {
+    let leaf = (0..11_usize).prop_map(Element::Num);
+    leaf.prop_recursive(4, 32, 5, |inner| {
+        prop::collection::vec(inner, 0..5).prop_map(Element::List)
+    })
+}
+
+/// It generates the inventory of day 1, with blank lines between elves.
+/// Calories are small, so many elves carry the same calories.
+fn inventory() -> impl Strategy<Value = String> {
+    let elf = prop::collection::vec(0..10_usize, 1..4);
+    prop::collection::vec(elf, 0..30).prop_map(|elves| {
+        let elves: Vec<_> = elves
+            .iter()
+            .map(|food| {
+                food.iter()
+                    .map(|c| c.to_string() + "\n")
+                    .collect::<String>()
+            })
+            .collect();
+        elves.join("\n")
+    })
+}
+
+/// It writes the rules of a cyclic game with `n` moves. Moves are `E0`, `E1`... in the elf's
+/// column and `M0`, `M1`... in mine, and the outcomes are `L`, `D`, and `W`.
+fn cyclic_rules(n: usize) -> String {
+    let mut res = "cyclic = true\n[outcomes]\n".to_string();
+    res.push_str("lose = { letter = \"L\", points = 0 }\n");
+    res.push_str("draw = { letter = \"D\", points = 3 }\n");
+    res.push_str("win = { letter = \"W\", points = 6 }\n");
+    for i in 0..n {
+        res.push_str(&format!(
+            "[[moves]]\nname = \"move{i}\"\nelf = \"E{i}\"\nme = \"M{i}\"\npoints = {}\n",
+            i + 1
+        ));
+    }
+    res
+}
+
+/// It generates the bags of day 3 with `compartments` compartments of the same size.
+/// Bags have few different items, so they often have zero or several items in common.
+fn bags(compartments: usize) -> impl Strategy<Value = Vec<String>> {
+    let item = prop::sample::select(vec!['a', 'b', 'c', 'Y', 'Z']);
+    (1..4_usize).prop_flat_map(move |size| {
+        let bag = prop::collection::vec(item.clone(), compartments * size);
+        prop::collection::vec(bag.prop_map(String::from_iter), 1..10)
+    })
+}
+
+/// It returns the only item in all the given sets, if there is exactly one.
+fn only_common_item<'a>(items: impl Iterator<Item = &'a str>) -> Option<char> {
+    let sets = items.map(|s| s.chars().collect::<BTreeSet<_>>());
+    let common = sets.reduce(|a, b| a.intersection(&b).copied().collect())?;
+    match common.len() {
+        1 => common.into_iter().next(),
+        _ => None,
+    }
+}
+
+/// It generates the areas of an elf (e.g., `3-7`). Areas are small, so ranges overlap often.
+fn areas() -> impl Strategy<Value = ElfAreas> {
+    (0..40_usize, 0..10_usize).prop_map(|(start, len)| (start, start + len))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn snafu_decimal_round_trip(n in 0..=i64::MAX) {
+        let snafu = dec_to_snafu(n);
+        prop_assert_eq!(read_input(&snafu).unwrap(), vec![n], "{}", snafu);
+    }
+
+    #[test]
+    fn snafu_canonical_round_trip(snafu in snafu()) {
+        let n = read_input(&snafu).unwrap()[0];
+        prop_assert!(n >= 0);
+        prop_assert_eq!(dec_to_snafu(n), snafu);
+    }
+
+    #[test]
+    fn snafu_sum(numbers in prop::collection::vec(0..1_000_000_000_000_i64, 1..20)) {
+        let input: String = numbers.iter().map(|&n| dec_to_snafu(n) + "\n").collect();
+        let expected = dec_to_snafu(numbers.iter().sum());
+        prop_assert_eq!(solve(25, 1, &input).unwrap(), expected);
+    }
+
+    #[test]
+    fn packet_display_round_trip(a in element()) {
+        prop_assert_eq!(a.to_string().parse::<Element>().unwrap(), a);
+    }
+
+    #[test]
+    fn packet_order_is_reflexive(a in element()) {
+        prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+
+    #[test]
+    fn packet_order_is_antisymmetric(a in element(), b in element()) {
+        prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        if a == b {
+            prop_assert_eq!(a.cmp(&b), Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn packet_order_is_transitive(a in element(), b in element(), c in element()) {
+        let mut packets = [a, b, c];
+        packets.sort();
+        prop_assert!(packets[0] <= packets[1] && packets[1] <= packets[2]);
+        prop_assert!(packets[0] <= packets[2]);
+    }
+
+    #[test]
+    fn packet_order_wraps_numbers(n in 0..11_usize, b in element()) {
+        let wrapped = Element::List(vec![Element::Num(n)]);
+        prop_assert_eq!(Element::Num(n).cmp(&b), wrapped.cmp(&b));
+    }
+
+    #[test]
+    fn top_elves_match_the_sorted_inventory(input in inventory(), k in 0..40_usize) {
+        let calories = day01::sum_calories(&day01::read_input(&input).unwrap());
+        let mut expected: Vec<_> = calories.into_iter().enumerate().collect();
+        // Stable sort, so ties keep the order of the input
+        expected.sort_by_key(|&(_, calories)| Reverse(calories));
+        expected.truncate(k);
+        let top = top_elves(input.as_bytes(), k).unwrap();
+        let top: Vec<_> = top.iter().map(|elf| (elf.index, elf.calories)).collect();
+        prop_assert_eq!(top, expected);
+    }
+
+    #[test]
+    fn inventory_ranks_match_the_top_elves(calories in prop::collection::vec(0..10_usize, 1..30)) {
+        let inventory = Inventory::new(calories);
+        let top = inventory.top(inventory.len());
+        prop_assert_eq!(inventory.rank(top[0].index), Some(1));
+        for (i, pair) in top.windows(2).enumerate() {
+            let (a, b) = (inventory.rank(pair[0].index).unwrap(), inventory.rank(pair[1].index).unwrap());
+            prop_assert!(pair[0].calories >= pair[1].calories);
+            // Ties share their rank, and the rest of elves are ranked by position
+            match pair[0].calories == pair[1].calories {
+                true => prop_assert_eq!(a, b),
+                false => prop_assert_eq!(b, i + 2),
+            }
+        }
+    }
+
+    #[test]
+    fn inventory_statistics_are_consistent(calories in prop::collection::vec(0..1000_usize, 1..30), p in 0..=100_u32, threshold in 0..1000_usize, n_buckets in 1..20_usize) {
+        let inventory = Inventory::new(calories.clone());
+        let (min, max) = (inventory.min().unwrap(), inventory.max().unwrap());
+        let (mean, median) = (inventory.mean().unwrap(), inventory.median().unwrap());
+        prop_assert_eq!(inventory.total(), Some(calories.iter().sum()));
+        prop_assert!(min as f64 <= mean && mean <= max as f64);
+        prop_assert!(min as f64 <= median && median <= max as f64);
+        prop_assert_eq!(inventory.percentile(0.), Some(min));
+        prop_assert_eq!(inventory.percentile(100.), Some(max));
+        // At least p% of the elves carry the percentile or fewer calories
+        let percentile = inventory.percentile(p as f64).unwrap();
+        let below = calories.iter().filter(|&&c| c <= percentile).count();
+        prop_assert!(below * 100 >= p as usize * calories.len());
+        let above = calories.iter().filter(|&&c| c > threshold).count();
+        prop_assert_eq!(inventory.above(threshold).len(), above);
+        let histogram = inventory.histogram(n_buckets);
+        prop_assert!(histogram.len() <= n_buckets);
+        prop_assert_eq!(histogram.iter().map(|b| b.count).sum::<usize>(), calories.len());
+        prop_assert_eq!((histogram[0].start, histogram[histogram.len() - 1].end), (min, max));
+    }
+
+    #[test]
+    fn cyclic_games_are_fair(n in (0..8_usize).prop_map(|n| 2 * n + 1)) {
+        let rules = Rules::parse(&cyclic_rules(n)).unwrap();
+        for a in (0..n).map(Symbol) {
+            let wins = (0..n).map(Symbol).filter(|&b| rules.outcome(b, a) == Outcome::Win);
+            prop_assert_eq!(wins.count(), (n - 1) / 2);
+            prop_assert_eq!(rules.outcome(a, a), Outcome::Draw);
+            for b in (0..n).map(Symbol).filter(|&b| b != a) {
+                let (ab, ba) = (rules.outcome(a, b), rules.outcome(b, a));
+                prop_assert!(ab != Outcome::Draw && ab != ba);
+            }
+        }
+    }
+
+    // Games with a single move cannot be won or lost
+    #[test]
+    fn outcomes_of_the_guide_are_respected(
+        n in (1..8_usize).prop_map(|n| 2 * n + 1),
+        rounds in prop::collection::vec((0..15_usize, prop::sample::select(vec!["L", "D", "W"])), 0..30),
+    ) {
+        let rules = Rules::parse(&cyclic_rules(n)).unwrap();
+        let guide: String = rounds.iter().map(|(elf, me)| format!("E{} {}\n", elf % n, me)).collect();
+        let plays = rules.read_input_2(&guide).unwrap();
+        for (&(elf, me), (_, outcome)) in plays.iter().zip(&rounds) {
+            prop_assert_eq!(rules.outcome_letter(rules.outcome(elf, me)), *outcome);
+        }
+    }
+
+    #[test]
+    fn optimal_plays_beat_every_decoding(
+        n in (0..3_usize).prop_map(|n| 2 * n + 1),
+        rounds in prop::collection::vec((0..15_usize, 0..15_usize), 0..30),
+    ) {
+        let rules = Rules::parse(&cyclic_rules(n)).unwrap();
+        let guide: String = rounds.iter().map(|(elf, me)| format!("E{} M{}\n", elf % n, me % n)).collect();
+        let decodings = rules.decodings(&guide).unwrap();
+        prop_assert_eq!(decodings.len(), (1..=n).product::<usize>());
+        // The decoding of the rules is one of them
+        let score = rules.total_points(&rules.read_input_1(&guide).unwrap());
+        let identity = Decoding((0..n).map(Symbol).collect());
+        prop_assert!(decodings.contains(&(identity, score)));
+        let best = rules.total_points(&rules.optimal_plays(&guide).unwrap());
+        prop_assert!(decodings.windows(2).all(|w| w[0].1 >= w[1].1));
+        prop_assert!(decodings.iter().all(|&(_, score)| score <= best));
+    }
+
+    #[test]
+    fn item_sets_match_the_sets_of_the_standard_library(a in "[a-zA-Z]{0,20}", b in "[a-zA-Z]{0,20}") {
+        let (set_a, set_b): (ItemSet, ItemSet) = (a.parse().unwrap(), b.parse().unwrap());
+        let (std_a, std_b): (BTreeSet<_>, BTreeSet<_>) = (a.chars().collect(), b.chars().collect());
+        let sorted = |items: BTreeSet<char>| {
+            let mut items: Vec<_> = items.into_iter().collect();
+            items.sort_by_key(|&c| get_priority(c));
+            items
+        };
+        prop_assert_eq!(set_a.len(), std_a.len());
+        prop_assert_eq!(set_a.items().collect::<Vec<_>>(), sorted(std_a.clone()));
+        prop_assert_eq!((set_a | set_b).items().collect::<Vec<_>>(), sorted(&std_a | &std_b));
+        prop_assert_eq!((set_a & set_b).items().collect::<Vec<_>>(), sorted(&std_a & &std_b));
+        prop_assert_eq!((set_a - set_b).items().collect::<Vec<_>>(), sorted(&std_a - &std_b));
+        prop_assert_eq!(set_a.to_string().parse::<ItemSet>().unwrap(), set_a);
+    }
+
+    #[test]
+    fn bags_need_exactly_one_duplicate((compartments, bags) in (1..5_usize).prop_flat_map(|c| (Just(c), bags(c)))) {
+        let input = bags.join("\n");
+        let day = Day03::with_sizes(&input, compartments, 1).unwrap();
+        let duplicates: Option<Vec<_>> = bags
+            .iter()
+            .map(|bag| {
+                let size = bag.len() / compartments;
+                only_common_item((0..compartments).map(|i| &bag[i * size..(i + 1) * size]))
+            })
+            .collect();
+        let expected = duplicates.map(|d| d.into_iter().filter_map(get_priority).sum::<usize>());
+        prop_assert_eq!(day.part1().ok(), expected);
+    }
+
+    #[test]
+    fn bags_only_contain_letters(line in "\\PC{0,12}", compartments in 1..4_usize) {
+        // Other characters (even the ones with several bytes) are errors, never panics
+        let day = Day03::with_sizes(&line, compartments, 1);
+        let letters = line.chars().all(|c| c.is_ascii_alphabetic());
+        let fits = line.chars().count().is_multiple_of(compartments);
+        prop_assert_eq!(day.is_ok(), letters && fits);
+    }
+
+    #[test]
+    fn groups_need_exactly_one_badge(group_size in 1..5_usize, bags in bags(1)) {
+        let input = bags.join("\n");
+        let day = Day03::with_sizes(&input, 1, group_size).unwrap();
+        let badges: Option<Vec<_>> = match bags.len() % group_size {
+            0 => bags.chunks(group_size).map(|g| only_common_item(g.iter().map(String::as_str))).collect(),
+            _ => None,
+        };
+        let expected = badges.map(|b| b.into_iter().filter_map(get_priority).sum::<usize>());
+        prop_assert_eq!(day.part2().ok(), expected);
+    }
+
+    #[test]
+    fn overlaps_are_symmetric(a in areas(), b in areas()) {
+        prop_assert_eq!(fully_overlaps(&a, &b), fully_overlaps(&b, &a));
+        prop_assert_eq!(overlaps(&a, &b), overlaps(&b, &a));
+    }
+
+    #[test]
+    fn overlaps_are_reflexive(a in areas()) {
+        prop_assert!(fully_overlaps(&a, &a));
+        prop_assert!(overlaps(&a, &a));
+    }
+
+    #[test]
+    fn full_overlaps_are_overlaps(a in areas(), b in areas()) {
+        prop_assert!(!fully_overlaps(&a, &b) || overlaps(&a, &b));
+    }
+
+    #[test]
+    fn overlaps_match_the_areas(a in areas(), b in areas()) {
+        let (range_a, range_b) = (a.0..=a.1, b.0..=b.1);
+        let common = range_a.clone().filter(|x| range_b.contains(x)).count();
+        prop_assert_eq!(overlaps(&a, &b), common > 0);
+        let smallest = range_a.count().min(range_b.count());
+        prop_assert_eq!(fully_overlaps(&a, &b), common == smallest);
+    }
+}
+
+#[test]
+fn bags_and_groups_cannot_be_empty() {
+    for (compartments, group_size) in [(0, 3), (2, 0), (0, 0)] {
+        let error = Day03::with_sizes("vJrwpWtwJgWr\n", compartments, group_size).unwrap_err();
+        assert_eq!(
+            error,
+            AocError::invalid_argument(
+                "bags need at least one compartment, and groups at least one elf"
+            )
+        );
+    }
+}
+
+#[test]
+fn inventory_total_may_overflow() {
+    let inventory = Inventory::new(vec![usize::MAX, 1]);
+    assert_eq!(inventory.total(), None);
+    // The rest of statistics do not need the total
+    assert_eq!(inventory.max(), Some(usize::MAX));
+    assert!(inventory.mean().unwrap() > 0.);
+}
+
+// Rock, paper, scissors is the cyclic game with three moves, so both rules must be the same
+#[test]
+fn cyclic_rock_paper_scissors_is_the_classic_game() {
+    let rules = CLASSIC_RULES.replace("beats = ", "# beats = ").replacen(
+        "[outcomes]",
+        "cyclic = true\n[outcomes]",
+        1,
+    );
+    assert_eq!(Rules::parse(&rules).unwrap(), Rules::default());
+}
+
+#[test]
+fn guides_may_only_make_sense_for_one_part() {
+    let path = format!("{}/data/02_rpsls.toml", env!("CARGO_MANIFEST_DIR"));
+    let rules = Rules::parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+    // V and W are moves, but not outcomes
+    let day = Day02::with_rules(rules.clone(), "A V\nB W\n").unwrap();
+    assert!(day.part1().is_ok());
+    assert!(day.part2().is_err());
+    // F is not a move of the elf, so the guide makes no sense at all
+    assert!(Day02::with_rules(rules, "A X\nF Y\n").is_err());
+}
@@ -0,0 +1,127 @@
+//! Golden tests: every day must solve the example of its puzzle statement.
+//! Examples live in `data/NN_sample.txt`, next to the real inputs.
+
+use aoc_2022::days::Day15;
+use aoc_2022::{solve, Solution};
+use std::fs;
+
+/// Image rendered by the CRT of the tenth day with its example (I print dark pixels as blanks).
+const DAY_10_CRT: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+/// It reads the example of a given day.
+fn sample(day: usize) -> String {
+    let path = format!("{}/data/{:02}_sample.txt", env!("CARGO_MANIFEST_DIR"), day);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("unable to read {}: {}", path, e))
+}
+
+/// It solves a part of the example of a given day and checks the answer.
+fn check(day: usize, part: usize, expected: &str) {
+    match solve(day, part, &sample(day)) {
+        Ok(answer) => assert_eq!(expected, answer, "day {} part {}", day, part),
+        Err(e) => panic!("day {} part {}: {}", day, part, e),
+    }
+}
+
+/// It creates a test for each part of the given days.
+macro_rules! samples {
+    ($($day:literal => $part_1:ident: $answer_1:expr, $part_2:ident: $answer_2:expr;)*) => {
+        $(
+            #[test]
+            fn $part_1() {
+                check($day, 1, &$answer_1.to_string());
+            }
+
+            #[test]
+            fn $part_2() {
+                check($day, 2, &$answer_2.to_string());
+            }
+        )*
+    };
+}
+
+samples! {
+    1 => day01_part1: 24000, day01_part2: 45000;
+    2 => day02_part1: 15, day02_part2: 12;
+    3 => day03_part1: 157, day03_part2: 70;
+    4 => day04_part1: 2, day04_part2: 4;
+    5 => day05_part1: "CMZ", day05_part2: "MCD";
+    6 => day06_part1: 7, day06_part2: 19;
+    7 => day07_part1: 95437, day07_part2: 24933642;
+    8 => day08_part1: 21, day08_part2: 8;
+    9 => day09_part1: 13, day09_part2: 1;
+    10 => day10_part1: 13140, day10_part2: DAY_10_CRT.replace('.', " ");
+    11 => day11_part1: 10605, day11_part2: 2713310158u64;
+    12 => day12_part1: 31, day12_part2: 29;
+    13 => day13_part1: 13, day13_part2: 140;
+    14 => day14_part1: 24, day14_part2: 93;
+    16 => day16_part1: 1651, day16_part2: 1707;
+    17 => day17_part1: 3068, day17_part2: 1514285714288u64;
+    18 => day18_part1: 64, day18_part2: 58;
+    20 => day20_part1: 3, day20_part2: 1623178306;
+    21 => day21_part1: 152, day21_part2: 301;
+    22 => day22_part1: 6032, day22_part2: 5031;
+    23 => day23_part1: 110, day23_part2: 20;
+    24 => day24_part1: 18, day24_part2: 54;
+    25 => day25_part1: "2=-1=0", day25_part2: "Merry Christmas!";
+}
+
+// The example of the fifteenth day studies row 10 and a search space up to 20 (instead of
+// row 2000000 and 4000000), so it cannot go through `solve`.
+
+#[test]
+fn day15_part1() {
+    let day = Day15::with_params(&sample(15), 10, 20).unwrap();
+    assert_eq!(26, day.part1().unwrap());
+}
+
+#[test]
+fn day15_part2() {
+    let day = Day15::with_params(&sample(15), 10, 20).unwrap();
+    assert_eq!(56000011, day.part2().unwrap());
+}
+
+// The example of the nineteenth day is harder than the real input.
+// Run the second part with `cargo test --release -- --ignored`.
+
+#[test]
+fn day19_part1() {
+    check(19, 1, "33");
+}
+
+#[test]
+#[ignore = "it takes about a minute in release mode"]
+fn day19_part2() {
+    check(19, 2, "3472");
+}
+
+// Errors found after parsing still point to the line of the input that causes them
+
+#[test]
+fn day05_moves_too_many_crates() {
+    let input = sample(5).replace("move 2 from 2 to 1", "move 5 from 2 to 1");
+    let error = solve(5, 1, &input).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 8, column 1: this move takes 5 crates from stack 2, but it only has 2"
+    );
+}
+
+#[test]
+fn day11_throws_to_unknown_monkey() {
+    let input = sample(11).replace(
+        "throw to monkey 1\n    If false: throw to monkey 3",
+        "throw to monkey 1\n    If false: throw to monkey 4",
+    );
+    let error = solve(11, 1, &input).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "line 20, column 31: monkey 4 does not exist"
+    );
+}